@@ -1,3 +1,5 @@
+use std::{cmp::Ordering, rc::Rc};
+
 use funlang_derive::Ast;
 use funlang_error::ErrorCascade;
 
@@ -5,19 +7,67 @@ use crate::{
     environment::Environment,
     error,
     errors::InterpreterError,
-    functions::Callable,
+    functions::{callable_name, compose, partial, Callable, PendingCall},
     literal::LiteralData,
     parse_string_to_num,
+    range::Range,
     token::{Token, TokenType},
 };
 
-use super::traits::Evaluable;
+use super::{
+    stmt::Stmt,
+    traits::{Evaluable, Executable},
+};
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Wildcard,
+    Binding(Token),
+    Literal(LiteralData),
+    Range(Range),
+}
+
+impl Pattern {
+    pub fn is_irrefutable(&self) -> bool {
+        matches!(self, Self::Wildcard | Self::Binding(_))
+    }
+
+    fn matches(&self, value: &LiteralData) -> bool {
+        match (self, value) {
+            (Self::Wildcard | Self::Binding(_), _) => true,
+            (Self::Literal(literal), value) => literal == value,
+            (Self::Range(range_value), LiteralData::Integer(integer_value)) => {
+                range_value.contains_integer(*integer_value)
+            }
+            (Self::Range(range_value), LiteralData::Float(float_value)) => {
+                range_value.contains(*float_value)
+            }
+            (Self::Range(_), _) => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Argument {
+    pub name: Option<Token>,
+    pub value: Expr,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Expr>,
+    pub body: Expr,
+}
 
 #[derive(Ast, Debug, Clone)]
 pub enum Expr {
     #[production(name: Token, value: Expr)]
     Assign(Box<AssignExpr>),
 
+    #[production(name: Token, operator: Token, value: Expr)]
+    CompoundAssign(Box<CompoundAssignExpr>),
+
     #[production(left: Expr, operator: Token, right: Expr)]
     Binary(Box<BinaryExpr>),
 
@@ -36,8 +86,61 @@ pub enum Expr {
     #[production(left: Expr, operator: Token, right: Expr)]
     Logical(Box<LogicalExpr>),
 
-    #[production(callee: Expr, paren: Token, arguments: Vec<Expr>)]
+    #[production(callee: Expr, paren: Token, arguments: Vec<Argument>, optional: bool)]
     Call(Box<CallExpr>),
+
+    #[production(callee: Expr, paren: Token, arguments: Vec<Argument>)]
+    Partial(Box<PartialExpr>),
+
+    #[production(object: Expr, name: Token, optional: bool)]
+    Get(Box<GetExpr>),
+
+    #[production(expression: Expr)]
+    OptionalChain(Box<OptionalChainExpr>),
+
+    #[production(condition: Expr, question: Token, then_branch: Expr, else_branch: Expr)]
+    Conditional(Box<ConditionalExpr>),
+
+    #[production(parts: Vec<Expr>)]
+    Interpolation(Box<InterpolationExpr>),
+
+    #[production(start: Expr, operator: Token, end: Expr)]
+    Range(Box<RangeExpr>),
+
+    #[production(object: Expr, bracket: Token, index: Expr)]
+    Index(Box<IndexExpr>),
+
+    #[production(keyword: Token, subject: Expr, arms: Vec<MatchArm>)]
+    Match(Box<MatchExpr>),
+
+    #[production(keyword: Token, body: Stmt)]
+    Block(Box<BlockExpr>),
+}
+
+impl Expr {
+    pub fn evaluate_tail(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        match self {
+            Self::Call(call_expr) => call_expr.call(environment, true),
+            Self::Grouping(grouping_expr) => grouping_expr.expression.evaluate_tail(environment),
+            Self::Match(match_expr) => match_expr.select(environment, true),
+            Self::Block(block_expr) => block_expr.body.execute_tail(environment),
+            Self::Conditional(conditional_expr) => {
+                if conditional_expr
+                    .condition
+                    .evaluate(environment)?
+                    .is_truthy()?
+                {
+                    conditional_expr.then_branch.evaluate_tail(environment)
+                } else {
+                    conditional_expr.else_branch.evaluate_tail(environment)
+                }
+            }
+            _ => self.evaluate(environment),
+        }
+    }
 }
 
 impl Evaluable<LiteralData> for Expr {
@@ -52,8 +155,239 @@ impl Evaluable<LiteralData> for Expr {
             Self::Grouping(grouping_expr) => grouping_expr.evaluate(environment),
             Self::Variable(variable_expr) => variable_expr.evaluate(environment),
             Self::Assign(assignment_expr) => assignment_expr.evaluate(environment),
+            Self::CompoundAssign(compound_assign_expr) => {
+                compound_assign_expr.evaluate(environment)
+            }
             Self::Logical(logical_expr) => logical_expr.evaluate(environment),
             Self::Call(call_expr) => call_expr.evaluate(environment),
+            Self::Get(get_expr) => get_expr.evaluate(environment),
+            Self::Interpolation(interpolation_expr) => interpolation_expr.evaluate(environment),
+            Self::Range(range_expr) => range_expr.evaluate(environment),
+            Self::Index(index_expr) => index_expr.evaluate(environment),
+            Self::Match(match_expr) => match_expr.evaluate(environment),
+            Self::Partial(partial_expr) => partial_expr.evaluate(environment),
+            Self::Block(block_expr) => block_expr.body.execute(environment),
+            Self::OptionalChain(optional_chain_expr) => {
+                match optional_chain_expr.expression.evaluate(environment) {
+                    Err(error) if matches!(error.error_type, InterpreterError::ShortCircuit) => {
+                        Ok(LiteralData::None)
+                    }
+                    result => result,
+                }
+            }
+            Self::Conditional(conditional_expr) => {
+                if conditional_expr
+                    .condition
+                    .evaluate(environment)?
+                    .is_truthy()?
+                {
+                    conditional_expr.then_branch.evaluate(environment)
+                } else {
+                    conditional_expr.else_branch.evaluate(environment)
+                }
+            }
+        }
+    }
+}
+
+impl Evaluable<LiteralData> for InterpolationExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let mut value = String::new();
+        for part in &self.parts {
+            value.push_str(&part.evaluate(environment)?.to_string());
+        }
+        Ok(LiteralData::String(value))
+    }
+}
+
+impl Evaluable<LiteralData> for RangeExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let span = self
+            .operator
+            .span
+            .clone()
+            .ok_or(error!(InterpreterError::MissingSpan))?;
+        let bound = |value: LiteralData| match value {
+            LiteralData::Integer(integer_value) => Ok(integer_value),
+            value => Err(error!(InterpreterError::InvalidRangeBound(
+                value.type_name().to_string()
+            ))
+            .set_span(span.clone().into())),
+        };
+
+        let start = bound(self.start.evaluate(environment)?)?;
+        let end = bound(self.end.evaluate(environment)?)?;
+        Ok(LiteralData::Range(Range::new(
+            start,
+            end,
+            self.operator.token_type == TokenType::DotDotEqual,
+        )))
+    }
+}
+
+fn index_sequence<T>(
+    items: &[T],
+    index: LiteralData,
+    item: fn(&T) -> LiteralData,
+    slice: fn(&[T]) -> LiteralData,
+) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+    let length = items.len();
+
+    match index {
+        LiteralData::Integer(integer_value) => usize::try_from(integer_value)
+            .ok()
+            .and_then(|position| items.get(position))
+            .map(item)
+            .ok_or(error!(InterpreterError::IndexOutOfBounds(
+                integer_value.to_string(),
+                length
+            ))),
+        LiteralData::Range(range_value) => {
+            let start = usize::try_from(range_value.start()).ok();
+            let end = usize::try_from(range_value.exclusive_end()).ok();
+            match (start, end) {
+                (Some(start), Some(end)) if start <= end && end <= length => {
+                    Ok(slice(&items[start..end]))
+                }
+                _ => Err(error!(InterpreterError::IndexOutOfBounds(
+                    range_value.to_string(),
+                    length
+                ))),
+            }
+        }
+        index => Err(error!(InterpreterError::InvalidIndex(
+            index.type_name().to_string()
+        ))),
+    }
+}
+
+impl Evaluable<LiteralData> for IndexExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let span = self
+            .bracket
+            .span
+            .clone()
+            .ok_or(error!(InterpreterError::MissingSpan))?;
+        let object = self.object.evaluate(environment)?;
+        let index = self.index.evaluate(environment)?;
+
+        match object {
+            LiteralData::String(string_value) => {
+                let characters: Vec<char> = string_value.chars().collect();
+                index_sequence(
+                    &characters,
+                    index,
+                    |character| LiteralData::String(character.to_string()),
+                    |characters| LiteralData::String(characters.iter().collect()),
+                )
+                .map_err(|error| error.set_span(span.into()))
+            }
+            LiteralData::List(list_value) => index_sequence(
+                &list_value,
+                index,
+                |item| item.clone(),
+                |items| LiteralData::List(Rc::new(items.to_vec())),
+            )
+            .map_err(|error| error.set_span(span.into())),
+            object => Err(error!(InterpreterError::InvalidIndexTarget(
+                object.type_name().to_string()
+            ))
+            .set_span(span.into())),
+        }
+    }
+}
+
+impl Evaluable<LiteralData> for MatchExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        self.select(environment, false)
+    }
+}
+
+impl MatchExpr {
+    fn select(
+        &self,
+        environment: &mut Environment,
+        is_tail: bool,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let subject = self.subject.evaluate(environment)?;
+
+        for arm in &self.arms {
+            if !arm.pattern.matches(&subject) {
+                continue;
+            }
+
+            let mut arm_environment = environment.create_scope();
+            if let Pattern::Binding(name) = &arm.pattern {
+                let name = name
+                    .lexeme
+                    .clone()
+                    .ok_or(error!(InterpreterError::MissingIdentifier))?;
+                arm_environment.define(
+                    &name,
+                    Expr::Literal(Box::new(LiteralExpr {
+                        literal: subject.clone(),
+                    })),
+                );
+            }
+
+            if let Some(guard) = &arm.guard {
+                if guard.evaluate(&mut arm_environment)?.is_falsy()? {
+                    continue;
+                }
+            }
+
+            return match is_tail {
+                true => arm.body.evaluate_tail(&mut arm_environment),
+                false => arm.body.evaluate(&mut arm_environment),
+            };
+        }
+
+        let span = self
+            .keyword
+            .span
+            .clone()
+            .ok_or(error!(InterpreterError::MissingSpan))?;
+        Err(error!(InterpreterError::NoMatchingArm(subject.to_string())).set_span(span.into()))
+    }
+}
+
+impl Evaluable<LiteralData> for GetExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let span = self
+            .name
+            .span
+            .clone()
+            .ok_or(error!(InterpreterError::MissingSpan))?;
+        let name = self
+            .name
+            .lexeme
+            .clone()
+            .ok_or(error!(InterpreterError::MissingIdentifier))?;
+
+        match self.object.evaluate(environment)? {
+            LiteralData::None if self.optional => Err(error!(InterpreterError::ShortCircuit)),
+            LiteralData::Module(module) => module
+                .get(&name)
+                .map_err(|error| error.set_span(span.into())),
+            object => Err(error!(InterpreterError::InvalidPropertyAccess(
+                object.type_name().to_string()
+            ))
+            .set_span(span.into())),
         }
     }
 }
@@ -62,19 +396,128 @@ impl Evaluable<LiteralData> for CallExpr {
     fn evaluate(
         &self,
         environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        self.call(environment, false)
+    }
+}
+
+impl CallExpr {
+    fn call(
+        &self,
+        environment: &mut Environment,
+        is_tail: bool,
     ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
         let callee = self.callee.evaluate(environment)?;
+        if self.optional && callee == LiteralData::None {
+            return Err(error!(InterpreterError::ShortCircuit));
+        }
 
         let mut arguments: Vec<LiteralData> = vec![];
+        let mut named_arguments: Vec<(String, LiteralData)> = vec![];
         for argument in &self.arguments {
-            arguments.push(argument.evaluate(environment)?);
+            let value = argument.value.evaluate(environment)?;
+            match &argument.name {
+                Some(name) => {
+                    named_arguments.push((name.lexeme.clone().unwrap_or_default(), value))
+                }
+                None => arguments.push(value),
+            }
+        }
+
+        let span = self
+            .paren
+            .span
+            .as_ref()
+            .ok_or(error!(InterpreterError::MissingSpan))?
+            .clone();
+
+        match callee {
+            LiteralData::Function(function_value) => {
+                let call_environment = function_value
+                    .bind_arguments(arguments, named_arguments)
+                    .map_err(|error| match error.span {
+                        Some(_) => error,
+                        None => error.set_span(span.clone().into()),
+                    })?;
+                if is_tail {
+                    return Err(error!(InterpreterError::TailCall(PendingCall::new(
+                        function_value,
+                        call_environment
+                    ))));
+                }
+                function_value.invoke(call_environment).map_err(|error| {
+                    match (&error.error_type, &error.span) {
+                        (InterpreterError::CallDepthExceeded(_), None) => {
+                            error.set_span(span.into())
+                        }
+                        _ => error,
+                    }
+                })
+            }
+            LiteralData::NativeFunction(function_value) => {
+                if let Some((name, _)) = named_arguments.first() {
+                    return Err(error!(InterpreterError::UnknownParameter(name.clone()))
+                        .set_span(span.into()));
+                }
+                function_value
+                    .call(environment, arguments)
+                    .map_err(|error| match error.span {
+                        Some(_) => error,
+                        None => error.set_span(span.into()),
+                    })
+            }
+            _ => Err(error!(InterpreterError::UncallableExpression).set_span(span.into())),
+        }
+    }
+}
+
+impl Argument {
+    pub fn is_placeholder(&self) -> bool {
+        match &self.value {
+            Expr::Variable(variable_expr) => {
+                self.name.is_none() && variable_expr.name.lexeme.as_deref() == Some("_")
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Evaluable<LiteralData> for PartialExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let callee = self.callee.evaluate(environment)?;
+        if !callee.is_callable() {
+            let span = self
+                .paren
+                .span
+                .clone()
+                .ok_or(error!(InterpreterError::MissingSpan))?;
+            return Err(error!(InterpreterError::UncallableExpression).set_span(span.into()));
         }
 
-        if let LiteralData::Function(function_value) = callee {
-            function_value.call(environment, arguments)
-        } else {
-            Err(error!(InterpreterError::UncallableExpression))
+        let mut template: Vec<Option<LiteralData>> = vec![];
+        let mut named_arguments: Vec<(String, LiteralData)> = vec![];
+        for argument in &self.arguments {
+            if argument.is_placeholder() {
+                template.push(None);
+                continue;
+            }
+            let value = argument.value.evaluate(environment)?;
+            match &argument.name {
+                Some(name) => {
+                    named_arguments.push((name.lexeme.clone().unwrap_or_default(), value))
+                }
+                None => template.push(Some(value)),
+            }
         }
+
+        Ok(LiteralData::NativeFunction(partial(
+            callee,
+            template,
+            named_arguments,
+        )))
     }
 }
 
@@ -92,6 +535,10 @@ impl Evaluable<LiteralData> for LogicalExpr {
                 self.left.evaluate(environment)?.is_truthy()?
                     && self.right.evaluate(environment)?.is_truthy()?,
             )),
+            TokenType::QuestionQuestion => match self.left.evaluate(environment)? {
+                LiteralData::None => self.right.evaluate(environment),
+                left => Ok(left),
+            },
             _ => Ok(LiteralData::Bool(false)),
         }
     }
@@ -122,6 +569,34 @@ impl Evaluable<LiteralData> for AssignExpr {
     }
 }
 
+impl Evaluable<LiteralData> for CompoundAssignExpr {
+    fn evaluate(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let name = self
+            .name
+            .lexeme
+            .clone()
+            .ok_or(error!(InterpreterError::MissingIdentifier))?;
+        let current = environment
+            .variable(&name)
+            .ok_or(error!(InterpreterError::InvalidIdentifier(name.clone())))?
+            .evaluate(environment)?;
+        let value = self.value.evaluate(environment)?;
+        let result = binary_operation(&self.operator, current, value, environment)?;
+
+        environment
+            .assign(
+                &name,
+                Expr::Literal(Box::new(LiteralExpr { literal: result })),
+            )
+            .or(Err(error!(InterpreterError::InvalidIdentifier(name))))?;
+
+        Ok(LiteralData::None)
+    }
+}
+
 impl Evaluable<LiteralData> for VariableExpr {
     fn evaluate(
         &self,
@@ -135,7 +610,7 @@ impl Evaluable<LiteralData> for VariableExpr {
         let expression = environment
             .variable(&identifier)
             .ok_or(error!(InterpreterError::InvalidIdentifier(identifier)))?;
-        Ok(expression.evaluate(environment)?)
+        expression.evaluate(environment)
     }
 }
 
@@ -157,6 +632,223 @@ impl Evaluable<LiteralData> for GroupingExpr {
     }
 }
 
+fn check_strict_operands(
+    operator: &TokenType,
+    left: &LiteralData,
+    right: &LiteralData,
+) -> Result<(), ErrorCascade<InterpreterError>> {
+    let both_numbers = left.is_number() && right.is_number();
+    let both_strings =
+        matches!(left, LiteralData::String(_)) && matches!(right, LiteralData::String(_));
+
+    let is_valid = match operator {
+        TokenType::Plus => both_numbers || both_strings,
+        TokenType::Minus
+        | TokenType::Star
+        | TokenType::Slash
+        | TokenType::Percent
+        | TokenType::Div
+        | TokenType::StarStar => both_numbers,
+        TokenType::Greater | TokenType::GreaterEqual | TokenType::Less | TokenType::LessEqual => {
+            !matches!(left, LiteralData::Bool(_)) && !matches!(right, LiteralData::Bool(_))
+        }
+        _ => true,
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(error!(InterpreterError::TypeMismatch(
+            operator.symbol(),
+            left.type_name().to_string(),
+            right.type_name().to_string()
+        )))
+    }
+}
+
+fn bitwise_operands(
+    operator: &TokenType,
+    left: &LiteralData,
+    right: &LiteralData,
+) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+    let (LiteralData::Integer(left_value), LiteralData::Integer(right_value)) = (left, right)
+    else {
+        return Err(error!(InterpreterError::TypeMismatch(
+            operator.symbol(),
+            left.type_name().to_string(),
+            right.type_name().to_string()
+        )));
+    };
+
+    match operator {
+        TokenType::Ampersand => Ok(LiteralData::Integer(left_value & right_value)),
+        TokenType::Pipe => Ok(LiteralData::Integer(left_value | right_value)),
+        TokenType::Caret => Ok(LiteralData::Integer(left_value ^ right_value)),
+        TokenType::LessLess | TokenType::GreaterGreater => u32::try_from(*right_value)
+            .ok()
+            .and_then(|amount| match operator {
+                TokenType::LessLess => left_value.checked_shl(amount),
+                _ => left_value.checked_shr(amount),
+            })
+            .map(LiteralData::Integer)
+            .ok_or(error!(InterpreterError::InvalidShiftAmount(
+                right_value.to_string()
+            ))),
+        token_type => Err(error!(InterpreterError::InvalidBinaryOperator(
+            token_type.symbol()
+        ))),
+    }
+}
+
+fn contains_operand(
+    left: &LiteralData,
+    right: &LiteralData,
+) -> Result<bool, ErrorCascade<InterpreterError>> {
+    match (left, right) {
+        (LiteralData::Integer(integer_value), LiteralData::Range(range_value)) => {
+            Ok(range_value.contains_integer(*integer_value))
+        }
+        (LiteralData::Float(float_value), LiteralData::Range(range_value)) => {
+            Ok(range_value.contains(*float_value))
+        }
+        (LiteralData::String(needle), LiteralData::String(haystack)) => {
+            Ok(haystack.contains(needle.as_str()))
+        }
+        _ => Err(error!(InterpreterError::TypeMismatch(
+            TokenType::In.symbol(),
+            left.type_name().to_string(),
+            right.type_name().to_string()
+        ))),
+    }
+}
+
+fn compare_operands(
+    operator: &TokenType,
+    left: &LiteralData,
+    right: &LiteralData,
+) -> Result<Ordering, ErrorCascade<InterpreterError>> {
+    match left.partial_cmp(right) {
+        Some(ordering) => Ok(ordering),
+        None if left.type_name() != right.type_name()
+            && !(left.is_number() && right.is_number()) =>
+        {
+            Err(error!(InterpreterError::TypeMismatch(
+                operator.symbol(),
+                left.type_name().to_string(),
+                right.type_name().to_string()
+            )))
+        }
+        None => Err(error!(InterpreterError::UnorderedValues(
+            left.to_string(),
+            right.to_string()
+        ))),
+    }
+}
+
+fn binary_operation(
+    operator_token: &Token,
+    left: LiteralData,
+    right: LiteralData,
+    environment: &Environment,
+) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+    let operator = &operator_token.token_type;
+    let span = operator_token
+        .span
+        .as_ref()
+        .ok_or(error!(InterpreterError::MissingSpan))?
+        .clone();
+
+    if environment.is_strict() {
+        check_strict_operands(operator, &left, &right)
+            .map_err(|error| error.set_span(span.clone().into()))?;
+    }
+
+    match operator {
+        TokenType::Plus => match left + right {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::AdditionException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::Minus => match left - right {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::SubtractionException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::Star => match left * right {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::MultiplicationException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::Slash => match left / right {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::DivisionException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::Percent => match left % right {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::ModuloException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::Div => match left.floor_div(right) {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::IntegerDivisionException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::StarStar => match left.pow(right) {
+            Ok(literal_value) => Ok(literal_value),
+            Err(embedded_error) => Err(error!(InterpreterError::ExponentiationException)
+                .set_span(span.into())
+                .set_embedded_error(Box::new(embedded_error))),
+        },
+        TokenType::LessLess | TokenType::GreaterGreater
+            if left.is_callable() && right.is_callable() =>
+        {
+            let name = format!(
+                "{} {} {}",
+                callable_name(&left),
+                operator.symbol(),
+                callable_name(&right)
+            );
+            Ok(LiteralData::NativeFunction(match operator {
+                TokenType::LessLess => compose(right, left, &name),
+                _ => compose(left, right, &name),
+            }))
+        }
+        TokenType::Ampersand
+        | TokenType::Pipe
+        | TokenType::Caret
+        | TokenType::LessLess
+        | TokenType::GreaterGreater => {
+            bitwise_operands(operator, &left, &right).map_err(|error| error.set_span(span.into()))
+        }
+        TokenType::Greater | TokenType::GreaterEqual | TokenType::Less | TokenType::LessEqual => {
+            let ordering = compare_operands(operator, &left, &right)
+                .map_err(|error| error.set_span(span.into()))?;
+            Ok(LiteralData::Bool(match operator {
+                TokenType::Greater => ordering.is_gt(),
+                TokenType::GreaterEqual => ordering.is_ge(),
+                TokenType::Less => ordering.is_lt(),
+                _ => ordering.is_le(),
+            }))
+        }
+        TokenType::In => contains_operand(&left, &right)
+            .map(LiteralData::Bool)
+            .map_err(|error| error.set_span(span.into())),
+        TokenType::BangEqual => Ok(LiteralData::Bool(left != right)),
+        TokenType::EqualEqual => Ok(LiteralData::Bool(left == right)),
+        token_type => Err(
+            error!(InterpreterError::InvalidBinaryOperator(token_type.symbol()))
+                .set_span(span.into()),
+        ),
+    }
+}
+
 impl Evaluable<LiteralData> for BinaryExpr {
     fn evaluate(
         &self,
@@ -164,46 +856,7 @@ impl Evaluable<LiteralData> for BinaryExpr {
     ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
         let left = self.left.evaluate(environment)?;
         let right = self.right.evaluate(environment)?;
-        let operator = &self.operator.token_type;
-        let span = self
-            .operator
-            .span
-            .as_ref()
-            .ok_or(error!(InterpreterError::MissingSpan))?
-            .clone();
-
-        match operator {
-            TokenType::Plus => match left + right {
-                Ok(literal_value) => Ok(literal_value),
-                Err(embedded_error) => Err(error!(InterpreterError::AdditionException)
-                    .set_embedded_error(Box::new(embedded_error))),
-            },
-            TokenType::Minus => match left - right {
-                Ok(literal_value) => Ok(literal_value),
-                Err(embedded_error) => Err(error!(InterpreterError::SubtractionException)
-                    .set_embedded_error(Box::new(embedded_error))),
-            },
-            TokenType::Star => match left * right {
-                Ok(literal_value) => Ok(literal_value),
-                Err(embedded_error) => Err(error!(InterpreterError::MultiplicationException)
-                    .set_embedded_error(Box::new(embedded_error))),
-            },
-            TokenType::Slash => match left / right {
-                Ok(literal_value) => Ok(literal_value),
-                Err(embedded_error) => Err(error!(InterpreterError::DivisionException)
-                    .set_embedded_error(Box::new(embedded_error))),
-            },
-            TokenType::Greater => Ok(LiteralData::Bool(left > right)),
-            TokenType::GreaterEqual => Ok(LiteralData::Bool(left >= right)),
-            TokenType::Less => Ok(LiteralData::Bool(left < right)),
-            TokenType::LessEqual => Ok(LiteralData::Bool(left <= right)),
-            TokenType::BangEqual => Ok(LiteralData::Bool(left != right)),
-            TokenType::EqualEqual => Ok(LiteralData::Bool(left == right)),
-            token_type => Err(error!(InterpreterError::InvalidBinaryOperator(
-                token_type.to_string()
-            ))
-            .set_span(span.into())),
-        }
+        binary_operation(&self.operator, left, right, environment)
     }
 }
 
@@ -225,42 +878,73 @@ impl Evaluable<LiteralData> for UnaryExpr {
             TokenType::Bang => match right {
                 LiteralData::None => Ok(LiteralData::Bool(true)),
                 LiteralData::Bool(bool) => Ok(LiteralData::Bool(!bool)),
-                LiteralData::Number(number) => {
-                    if number != 0.0 {
-                        Ok(LiteralData::Bool(true))
-                    } else {
-                        Ok(LiteralData::Bool(false))
-                    }
-                }
+                LiteralData::Integer(integer_value) => Ok(LiteralData::Bool(integer_value != 0)),
+                LiteralData::Float(float_value) => Ok(LiteralData::Bool(float_value != 0.0)),
                 LiteralData::String(string) => {
-                    if string != "".to_string() {
+                    if !string.is_empty() {
                         Ok(LiteralData::Bool(true))
                     } else {
                         Ok(LiteralData::Bool(false))
                     }
                 }
                 LiteralData::Function(_) => Ok(LiteralData::Bool(false)),
+                LiteralData::NativeFunction(_) => Ok(LiteralData::Bool(false)),
+                LiteralData::Module(_) | LiteralData::Range(_) | LiteralData::List(_) => {
+                    Ok(LiteralData::Bool(false))
+                }
             },
+            TokenType::Minus if environment.is_strict() && !right.is_number() => {
+                Err(error!(InterpreterError::InvalidOperandType(
+                    operator.symbol(),
+                    right.type_name().to_string()
+                ))
+                .set_span(span.into()))
+            }
             TokenType::Minus => match right {
                 LiteralData::None => Ok(LiteralData::Bool(true)),
-                LiteralData::Number(number_value) => Ok(LiteralData::Number(-number_value)),
+                LiteralData::Integer(integer_value) => integer_value
+                    .checked_neg()
+                    .map(LiteralData::Integer)
+                    .ok_or(error!(InterpreterError::IntegerOverflow).set_span(span.into())),
+                LiteralData::Float(float_value) => Ok(LiteralData::Float(-float_value)),
                 LiteralData::Bool(boolean_value) => {
-                    let boolean_value = if boolean_value { 1.0 } else { 0.0 };
-                    Ok(LiteralData::Number(-boolean_value))
+                    Ok(LiteralData::Integer(if boolean_value { -1 } else { 0 }))
                 }
                 LiteralData::String(string_value) => {
-                    let parsed_number = parse_string_to_num!(
+                    match parse_string_to_num!(
                         string_value,
                         error!(InterpreterError::InvalidParsedNumber(string_value))
-                    )?;
-                    Ok(LiteralData::Number(parsed_number))
+                    )? {
+                        LiteralData::Integer(integer_value) => integer_value
+                            .checked_neg()
+                            .map(LiteralData::Integer)
+                            .ok_or(error!(InterpreterError::IntegerOverflow).set_span(span.into())),
+                        LiteralData::Float(float_value) => Ok(LiteralData::Float(-float_value)),
+                        _ => Err(error!(InterpreterError::InvalidLiteralData)),
+                    }
+                }
+                LiteralData::Function(_) => Ok(LiteralData::Integer(-1)),
+                LiteralData::NativeFunction(_) => Ok(LiteralData::Integer(-1)),
+                LiteralData::Module(_) | LiteralData::Range(_) | LiteralData::List(_) => {
+                    Err(error!(InterpreterError::InvalidOperandType(
+                        operator.symbol(),
+                        right.type_name().to_string()
+                    ))
+                    .set_span(span.into()))
                 }
-                LiteralData::Function(_) => Ok(LiteralData::Number(-1.0)),
             },
-            token_type => Err(error!(InterpreterError::InvalidUnaryOperator(
-                token_type.to_string()
-            ))
-            .set_span(span.into())),
+            TokenType::Tilde => match right {
+                LiteralData::Integer(integer_value) => Ok(LiteralData::Integer(!integer_value)),
+                _ => Err(error!(InterpreterError::InvalidOperandType(
+                    operator.symbol(),
+                    right.type_name().to_string()
+                ))
+                .set_span(span.into())),
+            },
+            token_type => Err(
+                error!(InterpreterError::InvalidUnaryOperator(token_type.symbol()))
+                    .set_span(span.into()),
+            ),
         }
     }
 }
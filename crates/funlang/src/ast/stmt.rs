@@ -1,6 +1,8 @@
+use std::{fmt::Display, rc::Rc};
+
 use crate::{
     ast::expr::Expr, environment::Environment, error, errors::InterpreterError,
-    functions::Function, literal::LiteralData, token::Token,
+    functions::Function, iterator::ValueIterator, literal::LiteralData, token::Token, types::Type,
 };
 use funlang_derive::Ast;
 use funlang_error::ErrorCascade;
@@ -10,6 +12,183 @@ use super::{
     traits::{Evaluable, Executable},
 };
 
+#[derive(Debug, Clone)]
+pub enum DestructurePattern {
+    List(Token, Vec<Token>),
+    Object(Token, Vec<Token>),
+}
+
+impl DestructurePattern {
+    pub fn token(&self) -> &Token {
+        match self {
+            Self::List(bracket, _) | Self::Object(bracket, _) => bracket,
+        }
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        match self {
+            Self::List(_, names) | Self::Object(_, names) => names
+                .iter()
+                .filter_map(|name| name.lexeme.clone())
+                .filter(|name| name != "_")
+                .collect(),
+        }
+    }
+
+    fn element_count(value: &LiteralData) -> Option<usize> {
+        match value {
+            LiteralData::String(string_value) => Some(string_value.chars().count()),
+            LiteralData::Range(range_value) => Some(range_value.len()),
+            LiteralData::List(list_value) => Some(list_value.len()),
+            _ => None,
+        }
+    }
+
+    fn elements(value: &LiteralData) -> Option<Vec<LiteralData>> {
+        match value {
+            LiteralData::String(string_value) => Some(
+                string_value
+                    .chars()
+                    .map(|character| LiteralData::String(character.to_string()))
+                    .collect(),
+            ),
+            LiteralData::Range(range_value) => Some(
+                (0..range_value.len())
+                    .filter_map(|index| range_value.get(index))
+                    .map(LiteralData::Integer)
+                    .collect(),
+            ),
+            LiteralData::List(list_value) => Some(list_value.to_vec()),
+            _ => None,
+        }
+    }
+
+    fn bindings(
+        &self,
+        value: LiteralData,
+    ) -> Result<Vec<(String, LiteralData)>, ErrorCascade<InterpreterError>> {
+        let invalid_destructure = || {
+            error!(InterpreterError::InvalidDestructure(
+                self.to_string(),
+                value.type_name().to_string()
+            ))
+        };
+
+        match self {
+            Self::List(_, names) => {
+                let count = Self::element_count(&value).ok_or_else(invalid_destructure)?;
+                if count != names.len() {
+                    return Err(error!(InterpreterError::DestructureLength(
+                        names.len(),
+                        count
+                    )));
+                }
+                let elements = Self::elements(&value).ok_or_else(invalid_destructure)?;
+                Ok(names
+                    .iter()
+                    .filter_map(|name| name.lexeme.clone())
+                    .zip(elements)
+                    .filter(|(name, _)| name != "_")
+                    .collect())
+            }
+            Self::Object(_, names) => {
+                let LiteralData::Module(module) = &value else {
+                    return Err(invalid_destructure());
+                };
+                names
+                    .iter()
+                    .filter_map(|name| name.lexeme.clone())
+                    .map(|name| {
+                        let member = module.get(&name)?;
+                        Ok((name, member))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn bind(
+        &self,
+        environment: &mut Environment,
+        value: LiteralData,
+    ) -> Result<(), ErrorCascade<InterpreterError>> {
+        let span = self
+            .token()
+            .span
+            .clone()
+            .ok_or(error!(InterpreterError::MissingSpan))?;
+
+        let bindings = self.bindings(value).map_err(|error| match error.span {
+            Some(_) => error,
+            None => error.set_span(span.into()),
+        })?;
+        for (name, value) in bindings {
+            environment.define(
+                &name,
+                Expr::Literal(Box::new(LiteralExpr { literal: value })),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Display for DestructurePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = |names: &Vec<Token>| {
+            names
+                .iter()
+                .filter_map(|name| name.lexeme.clone())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        match self {
+            Self::List(_, list_names) => write!(f, "[{}]", names(list_names)),
+            Self::Object(_, object_names) => write!(f, "{{ {} }}", names(object_names)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub name: Token,
+    pub type_annotation: Option<Type>,
+    pub pattern: Option<DestructurePattern>,
+    pub default: Option<Expr>,
+    pub variadic: bool,
+}
+
+impl Parameter {
+    pub fn label(&self) -> String {
+        match &self.pattern {
+            Some(pattern) => pattern.to_string(),
+            None if self.variadic => format!("...{}", self.name.lexeme.clone().unwrap_or_default()),
+            None => self.name.lexeme.clone().unwrap_or_default(),
+        }
+    }
+
+    pub fn bind(
+        &self,
+        environment: &mut Environment,
+        value: LiteralData,
+    ) -> Result<(), ErrorCascade<InterpreterError>> {
+        match &self.pattern {
+            Some(pattern) => pattern.bind(environment, value),
+            None => {
+                let name = self
+                    .name
+                    .lexeme
+                    .clone()
+                    .ok_or(error!(InterpreterError::MissingIdentifier))?;
+                environment.define(
+                    &name,
+                    Expr::Literal(Box::new(LiteralExpr { literal: value })),
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
 #[derive(Ast, Debug, Clone)]
 pub enum Stmt {
     #[production(expression: Expr)]
@@ -18,20 +197,104 @@ pub enum Stmt {
     #[production(expression: Expr)]
     Print(Box<PrintStmt>),
 
-    #[production(name: Token, initializer: Option<Expr>)]
+    #[production(expression: Expr)]
+    Value(Box<ValueStmt>),
+
+    #[production(name: Token, type_annotation: Option<Type>, initializer: Option<Expr>, doc: Option<String>)]
     Variable(Box<VariableStmt>),
 
+    #[production(keyword: Token, pattern: DestructurePattern, initializer: Expr)]
+    Destructure(Box<DestructureStmt>),
+
     #[production(statements: Vec<Stmt>)]
     Block(Box<BlockStmt>),
 
     #[production(condition: Expr, then_branch: Stmt, else_branch: Option<Stmt>)]
     If(Box<IfStmt>),
 
-    #[production(condition: Expr, body: Stmt)]
+    #[production(condition: Expr, body: Stmt, increment: Option<Expr>, label: Option<Token>)]
     While(Box<WhileStmt>),
 
-    #[production(name: Token, params: Vec<Token>, body: Stmt)]
+    #[production(name: Token, keyword: Token, iterable: Expr, body: Stmt, label: Option<Token>)]
+    ForIn(Box<ForInStmt>),
+
+    #[production(keyword: Token, label: Option<Token>)]
+    Break(Box<BreakStmt>),
+
+    #[production(keyword: Token, label: Option<Token>)]
+    Continue(Box<ContinueStmt>),
+
+    #[production(name: Token, params: Vec<Parameter>, return_type: Option<Type>, body: Stmt, doc: Option<String>)]
     Function(Box<FunctionStmt>),
+
+    #[production(keyword: Token, value: Option<Expr>)]
+    Return(Box<ReturnStmt>),
+
+    #[production(name: Token, body: Stmt)]
+    Test(Box<TestStmt>),
+
+    #[production(keyword: Token, path: Token, alias: Token)]
+    Import(Box<ImportStmt>),
+
+    #[production(keyword: Token, declaration: Stmt)]
+    Export(Box<ExportStmt>),
+}
+
+fn is_loop_exit(
+    result: Result<LiteralData, ErrorCascade<InterpreterError>>,
+    label: &Option<Token>,
+) -> Result<bool, ErrorCascade<InterpreterError>> {
+    let Err(error) = result else {
+        return Ok(false);
+    };
+    let label = label.as_ref().and_then(|label| label.lexeme.as_ref());
+    let is_target = |target: &String| target.is_empty() || Some(target) == label;
+
+    match &error.error_type {
+        InterpreterError::Break(target) if is_target(target) => Ok(true),
+        InterpreterError::Continue(target) if is_target(target) => Ok(false),
+        _ => Err(error),
+    }
+}
+
+impl Stmt {
+    pub fn execute_tail(
+        &self,
+        environment: &mut Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        match self {
+            Self::Block(block_statement) => {
+                let mut environment = environment.create_scope();
+
+                let Some((last, statements)) = block_statement.statements.split_last() else {
+                    return Ok(LiteralData::None);
+                };
+                for statement in statements {
+                    statement.execute(&mut environment)?;
+                }
+                match last {
+                    Self::Value(_) | Self::Block(_) | Self::If(_) => {
+                        last.execute_tail(&mut environment)
+                    }
+                    _ => {
+                        last.execute(&mut environment)?;
+                        Ok(LiteralData::None)
+                    }
+                }
+            }
+            Self::Value(value_statement) => value_statement.expression.evaluate_tail(environment),
+            Self::If(if_statement) => {
+                if if_statement.condition.evaluate(environment)?.is_truthy()? {
+                    if_statement.then_branch.execute_tail(environment)
+                } else if let Some(else_branch) = &if_statement.else_branch {
+                    else_branch.execute_tail(environment)
+                } else {
+                    Ok(LiteralData::None)
+                }
+            }
+            _ => self.execute(environment),
+        }
+    }
 }
 
 impl Executable<LiteralData> for Stmt {
@@ -47,9 +310,13 @@ impl Executable<LiteralData> for Stmt {
             Self::Print(print_statement) => {
                 match print_statement.expression.evaluate(environment) {
                     Ok(evaluated_value) => {
-                        println!("{}", evaluated_value);
+                        environment
+                            .output()
+                            .print_line(&evaluated_value.to_string())
+                            .or(Err(error!(InterpreterError::OutputException)))?;
                         Ok(evaluated_value)
                     }
+                    Err(error) if error.error_type.is_control_flow() => Err(error),
                     Err(error) => Err(error!(InterpreterError::EvaluatationException)
                         .set_embedded_error(Box::new(error))),
                 }
@@ -58,10 +325,14 @@ impl Executable<LiteralData> for Stmt {
                 if let Some(name) = variable_statement.name.lexeme.as_ref() {
                     match variable_statement.as_ref().initializer {
                         Some(ref initializer) => {
-                            environment.define(&name, initializer.clone());
+                            let value = initializer.evaluate(environment)?;
+                            environment.define(
+                                name,
+                                Expr::Literal(Box::new(LiteralExpr { literal: value })),
+                            );
                         }
                         None => environment.define(
-                            &name,
+                            name,
                             Expr::Literal(Box::new(LiteralExpr {
                                 literal: LiteralData::None,
                             })),
@@ -70,23 +341,32 @@ impl Executable<LiteralData> for Stmt {
                 }
                 Ok(LiteralData::None)
             }
+            Self::Destructure(destructure_statement) => {
+                let value = destructure_statement.initializer.evaluate(environment)?;
+                destructure_statement.pattern.bind(environment, value)?;
+                Ok(LiteralData::None)
+            }
             Self::Block(block_statement) => {
                 let mut environment = environment.create_scope();
 
+                let mut value = LiteralData::None;
                 for statement in &block_statement.statements {
-                    statement.execute(&mut environment)?;
+                    value = statement.execute(&mut environment)?;
+                }
+                match block_statement.statements.last() {
+                    Some(Self::Value(_) | Self::Block(_) | Self::If(_)) => Ok(value),
+                    _ => Ok(LiteralData::None),
                 }
-                Ok(LiteralData::None)
             }
+            Self::Value(value_statement) => value_statement.expression.evaluate(environment),
             Self::If(if_statement) => {
                 if if_statement.condition.evaluate(environment)?.is_truthy()? {
-                    if_statement.then_branch.execute(environment)?;
+                    if_statement.then_branch.execute(environment)
+                } else if let Some(else_branch) = &if_statement.else_branch {
+                    else_branch.execute(environment)
                 } else {
-                    if let Some(else_branch) = &if_statement.else_branch {
-                        else_branch.execute(environment)?;
-                    }
+                    Ok(LiteralData::None)
                 }
-                Ok(LiteralData::None)
             }
             Self::While(while_statement) => {
                 while while_statement
@@ -94,18 +374,79 @@ impl Executable<LiteralData> for Stmt {
                     .evaluate(environment)?
                     .is_truthy()?
                 {
-                    while_statement.body.execute(environment)?;
+                    if is_loop_exit(
+                        while_statement.body.execute(environment),
+                        &while_statement.label,
+                    )? {
+                        break;
+                    }
+                    if let Some(increment) = &while_statement.increment {
+                        increment.evaluate(environment)?;
+                    }
+                }
+                Ok(LiteralData::None)
+            }
+            Self::ForIn(for_in_statement) => {
+                let name = for_in_statement
+                    .name
+                    .lexeme
+                    .clone()
+                    .ok_or(error!(InterpreterError::MissingIdentifier))?;
+                let span = for_in_statement
+                    .keyword
+                    .span
+                    .clone()
+                    .ok_or(error!(InterpreterError::MissingSpan))?;
+
+                let iterable = for_in_statement.iterable.evaluate(environment)?;
+                let mut iterator =
+                    ValueIterator::new(iterable).map_err(|error| error.set_span(span.into()))?;
+
+                while let Some(item) = iterator.next(environment)? {
+                    let mut loop_environment = environment.create_scope();
+                    loop_environment.define(
+                        &name,
+                        Expr::Literal(Box::new(LiteralExpr { literal: item })),
+                    );
+                    if is_loop_exit(
+                        for_in_statement.body.execute(&mut loop_environment),
+                        &for_in_statement.label,
+                    )? {
+                        break;
+                    }
                 }
                 Ok(LiteralData::None)
             }
+            Self::Break(break_statement) => Err(error!(InterpreterError::Break(
+                break_statement
+                    .label
+                    .as_ref()
+                    .and_then(|label| label.lexeme.clone())
+                    .unwrap_or_default()
+            ))),
+            Self::Continue(continue_statement) => Err(error!(InterpreterError::Continue(
+                continue_statement
+                    .label
+                    .as_ref()
+                    .and_then(|label| label.lexeme.clone())
+                    .unwrap_or_default()
+            ))),
             Self::Function(function_statement) => {
                 let name = function_statement
                     .name
                     .lexeme
                     .clone()
                     .ok_or(error!(InterpreterError::MissingIdentifier))?;
-                let arity = function_statement.params.len() as u32;
-                let function_value = Function::new(arity, function_statement.clone());
+                let arity = function_statement
+                    .params
+                    .iter()
+                    .filter(|param| param.default.is_none() && !param.variadic)
+                    .count() as u32;
+                let function_value = Function::new(
+                    arity,
+                    Rc::new(*function_statement.clone()),
+                    environment.clone(),
+                );
 
                 environment.define(
                     &name,
@@ -116,6 +457,50 @@ impl Executable<LiteralData> for Stmt {
 
                 Ok(LiteralData::None)
             }
+            Self::Return(return_statement) => {
+                let value = match &return_statement.value {
+                    Some(value) => value.evaluate_tail(environment)?,
+                    None => LiteralData::None,
+                };
+                Err(error!(InterpreterError::Return(value)))
+            }
+            Self::Test(_) => Ok(LiteralData::None),
+            Self::Import(import_statement) => {
+                let span = import_statement
+                    .path
+                    .span
+                    .clone()
+                    .ok_or(error!(InterpreterError::MissingSpan))?;
+                let path = import_statement
+                    .path
+                    .literal_data
+                    .as_ref()
+                    .ok_or(error!(InterpreterError::InvalidLiteralData))?
+                    .to_string();
+                let alias = import_statement
+                    .alias
+                    .lexeme
+                    .clone()
+                    .ok_or(error!(InterpreterError::MissingIdentifier))?;
+
+                let module = environment
+                    .loader()
+                    .load(&path, environment)
+                    .map_err(|error| match error.span {
+                        Some(_) => error,
+                        None => error.set_span(span.into()),
+                    })?;
+
+                environment.define(
+                    &alias,
+                    Expr::Literal(Box::new(LiteralExpr {
+                        literal: LiteralData::Module(module),
+                    })),
+                );
+
+                Ok(LiteralData::None)
+            }
+            Self::Export(export_statement) => export_statement.declaration.execute(environment),
         }
     }
 }
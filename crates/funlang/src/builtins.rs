@@ -0,0 +1,69 @@
+use crate::{
+    ast::expr::{Expr, LiteralExpr},
+    environment::Environment,
+    error,
+    errors::InterpreterError,
+    functions::{callable_arity, partial, NativeFunction},
+    literal::LiteralData,
+};
+
+fn define_native(environment: &mut Environment, function: NativeFunction) {
+    let name = function.name().to_owned();
+    environment.define(
+        &name,
+        Expr::Literal(Box::new(LiteralExpr {
+            literal: LiteralData::NativeFunction(function),
+        })),
+    );
+}
+
+pub fn define_builtins(environment: &mut Environment) {
+    define_native(
+        environment,
+        NativeFunction::new("assert", 1, |_environment, arguments| {
+            if arguments[0].is_truthy()? {
+                Ok(LiteralData::None)
+            } else {
+                Err(error!(InterpreterError::AssertionFailed))
+            }
+        }),
+    );
+
+    define_native(
+        environment,
+        NativeFunction::new("assert_eq", 2, |_environment, arguments| {
+            if arguments[0] == arguments[1] {
+                Ok(LiteralData::None)
+            } else {
+                Err(error!(InterpreterError::AssertionNotEqual(
+                    arguments[0].to_string(),
+                    arguments[1].to_string()
+                )))
+            }
+        }),
+    );
+
+    define_native(
+        environment,
+        NativeFunction::variadic("partial", 1, |_environment, mut arguments| {
+            let callee = arguments.remove(0);
+            if !callee.is_callable() {
+                return Err(error!(InterpreterError::UncallableExpression));
+            }
+            Ok(LiteralData::NativeFunction(partial(
+                callee,
+                arguments.into_iter().map(Some).collect(),
+                vec![],
+            )))
+        }),
+    );
+
+    define_native(
+        environment,
+        NativeFunction::new("arity", 1, |_environment, arguments| {
+            callable_arity(&arguments[0])
+                .map(|arity| LiteralData::Integer(arity as i64))
+                .ok_or(error!(InterpreterError::UncallableExpression))
+        }),
+    );
+}
@@ -0,0 +1,821 @@
+use std::collections::HashMap;
+
+use funlang_error::ErrorCascade;
+
+use crate::{
+    ast::{
+        expr::{Expr, Pattern},
+        stmt::{Parameter, Stmt},
+    },
+    error,
+    errors::TypeCheckerError,
+    token::{Token, TokenType},
+    types::Type,
+};
+
+#[derive(Debug, Clone)]
+struct Signature {
+    params: Vec<(String, Option<Type>)>,
+    required: usize,
+    variadic: bool,
+    return_type: Option<Type>,
+}
+
+impl Signature {
+    fn arity_error(&self, found: usize) -> TypeCheckerError {
+        if self.variadic {
+            TypeCheckerError::TooFewArguments(self.required as u32, found as u32)
+        } else if self.required == self.params.len() {
+            TypeCheckerError::InvalidArguments(self.params.len() as u32, found as u32)
+        } else {
+            TypeCheckerError::InvalidArgumentRange(
+                self.required as u32,
+                self.params.len() as u32,
+                found as u32,
+            )
+        }
+    }
+
+    fn is_annotated(&self) -> bool {
+        self.return_type.is_some()
+            || self
+                .params
+                .iter()
+                .any(|(_, type_annotation)| type_annotation.is_some())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Binding {
+    value_type: Type,
+    is_annotated: bool,
+    signature: Option<Signature>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tail {
+    value_type: Option<Type>,
+    is_missing: bool,
+}
+
+impl Tail {
+    const DIVERGES: Self = Self {
+        value_type: None,
+        is_missing: false,
+    };
+    const MISSING: Self = Self {
+        value_type: None,
+        is_missing: true,
+    };
+
+    fn value(value_type: Type) -> Self {
+        Self {
+            value_type: Some(value_type),
+            is_missing: false,
+        }
+    }
+
+    fn diverges(&self) -> bool {
+        self.value_type.is_none() && !self.is_missing
+    }
+
+    fn join(self, other: Self) -> Self {
+        let value_type = match (self.value_type, other.value_type) {
+            (Some(left), Some(right)) if left == right => Some(left),
+            (Some(_), Some(_)) => Some(Type::Any),
+            (left, right) => left.or(right),
+        };
+        Self {
+            value_type,
+            is_missing: self.is_missing || other.is_missing,
+        }
+    }
+}
+
+pub struct TypeChecker {
+    scopes: Vec<HashMap<String, Binding>>,
+    return_types: Vec<Option<Type>>,
+    warnings: Vec<ErrorCascade<TypeCheckerError>>,
+    tail: Tail,
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeChecker {
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![HashMap::new()],
+            return_types: vec![],
+            warnings: vec![],
+            tail: Tail::MISSING,
+        }
+    }
+
+    pub fn warnings(&self) -> &[ErrorCascade<TypeCheckerError>] {
+        &self.warnings
+    }
+
+    fn span_error(token: &Token, error_type: TypeCheckerError) -> ErrorCascade<TypeCheckerError> {
+        match &token.span {
+            Some(span) => error!(error_type).set_span(span.clone().into()),
+            None => error!(error_type),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Binding> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn declare(&mut self, name: &str, binding: Binding) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_owned(), binding);
+        }
+    }
+
+    fn assign(
+        &mut self,
+        name: &Token,
+        value_type: Type,
+    ) -> Result<(), ErrorCascade<TypeCheckerError>> {
+        let identifier = name.lexeme.clone().unwrap_or_default();
+        let binding = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(&identifier));
+
+        if let Some(binding) = binding {
+            if binding.is_annotated {
+                if !binding.value_type.accepts(&value_type) {
+                    return Err(Self::span_error(
+                        name,
+                        TypeCheckerError::MismatchedType(
+                            binding.value_type.to_string(),
+                            value_type.to_string(),
+                        ),
+                    ));
+                }
+            } else if binding.value_type != value_type {
+                binding.value_type = Type::Any;
+                binding.signature = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn numeric_result(left: Type, right: Type) -> Type {
+        match (left, right) {
+            (Type::Integer, Type::Integer) => Type::Integer,
+            (Type::Float, right) if right.is_numeric() => Type::Float,
+            (left, Type::Float) if left.is_numeric() => Type::Float,
+            (left, right) if left.is_numeric() && right.is_numeric() => Type::Number,
+            _ => Type::Any,
+        }
+    }
+
+    fn binary_result(operator: &TokenType, left: Type, right: Type) -> Type {
+        match operator {
+            TokenType::Plus if left == Type::String => Type::String,
+            TokenType::Plus
+            | TokenType::Minus
+            | TokenType::Star
+            | TokenType::Percent
+            | TokenType::Div => Self::numeric_result(left, right),
+            TokenType::StarStar if left.is_numeric() && right.is_numeric() => {
+                match Self::numeric_result(left, right) {
+                    Type::Integer => Type::Number,
+                    result => result,
+                }
+            }
+            TokenType::LessLess | TokenType::GreaterGreater
+                if left == Type::Function || right == Type::Function =>
+            {
+                Type::Function
+            }
+            TokenType::Ampersand
+            | TokenType::Pipe
+            | TokenType::Caret
+            | TokenType::LessLess
+            | TokenType::GreaterGreater => Type::Integer,
+            TokenType::Slash if left.is_numeric() && right.is_numeric() => Type::Float,
+            TokenType::Greater
+            | TokenType::GreaterEqual
+            | TokenType::Less
+            | TokenType::LessEqual
+            | TokenType::EqualEqual
+            | TokenType::BangEqual
+            | TokenType::In => Type::Bool,
+            _ => Type::Any,
+        }
+    }
+
+    fn infer(&mut self, expression: &Expr) -> Result<Type, ErrorCascade<TypeCheckerError>> {
+        match expression {
+            Expr::Literal(literal_expr) => Ok(Type::of(&literal_expr.literal)),
+            Expr::Grouping(grouping_expr) => self.infer(&grouping_expr.expression),
+            Expr::Variable(variable_expr) => {
+                let identifier = variable_expr.name.lexeme.clone().unwrap_or_default();
+                Ok(self
+                    .lookup(&identifier)
+                    .map(|binding| binding.value_type)
+                    .unwrap_or(Type::Any))
+            }
+            Expr::Assign(assign_expr) => {
+                let value_type = self.infer(&assign_expr.value)?;
+                self.assign(&assign_expr.name, value_type)?;
+                Ok(Type::Null)
+            }
+            Expr::Logical(logical_expr) => {
+                let left = self.infer(&logical_expr.left)?;
+                let right = self.infer(&logical_expr.right)?;
+                match logical_expr.operator.token_type {
+                    TokenType::QuestionQuestion if left == right => Ok(left),
+                    TokenType::QuestionQuestion => Ok(Type::Any),
+                    _ => Ok(Type::Bool),
+                }
+            }
+            Expr::Unary(unary_expr) => {
+                let right = self.infer(&unary_expr.right)?;
+                match unary_expr.operator.token_type {
+                    TokenType::Bang => Ok(Type::Bool),
+                    TokenType::Minus if right.is_numeric() => Ok(right),
+                    TokenType::Tilde => Ok(Type::Integer),
+                    _ => Ok(Type::Any),
+                }
+            }
+            Expr::Binary(binary_expr) => {
+                let left = self.infer(&binary_expr.left)?;
+                let right = self.infer(&binary_expr.right)?;
+                Ok(Self::binary_result(
+                    &binary_expr.operator.token_type,
+                    left,
+                    right,
+                ))
+            }
+            Expr::CompoundAssign(compound_assign_expr) => {
+                let value_type = self.infer(&compound_assign_expr.value)?;
+                let identifier = compound_assign_expr.name.lexeme.clone().unwrap_or_default();
+                let current_type = self
+                    .lookup(&identifier)
+                    .map(|binding| binding.value_type)
+                    .unwrap_or(Type::Any);
+                let result_type = Self::binary_result(
+                    &compound_assign_expr.operator.token_type,
+                    current_type,
+                    value_type,
+                );
+                self.assign(&compound_assign_expr.name, result_type)?;
+                Ok(Type::Null)
+            }
+            Expr::Range(range_expr) => {
+                self.infer(&range_expr.start)?;
+                self.infer(&range_expr.end)?;
+                Ok(Type::Range)
+            }
+            Expr::Index(index_expr) => {
+                let object_type = self.infer(&index_expr.object)?;
+                self.infer(&index_expr.index)?;
+                match object_type {
+                    Type::String => Ok(Type::String),
+                    _ => Ok(Type::Any),
+                }
+            }
+            Expr::Match(match_expr) => {
+                let subject_type = self.infer(&match_expr.subject)?;
+                let mut arm_types: Vec<Type> = vec![];
+
+                for arm in &match_expr.arms {
+                    let mut bindings: HashMap<String, Binding> = HashMap::new();
+                    if let Pattern::Binding(name) = &arm.pattern {
+                        bindings.insert(
+                            name.lexeme.clone().unwrap_or_default(),
+                            Binding {
+                                value_type: subject_type,
+                                is_annotated: false,
+                                signature: None,
+                            },
+                        );
+                    }
+
+                    self.scopes.push(bindings);
+                    let arm_type = arm
+                        .guard
+                        .as_ref()
+                        .map(|guard| self.infer(guard))
+                        .transpose()
+                        .and_then(|_| self.infer(&arm.body));
+                    self.scopes.pop();
+                    arm_types.push(arm_type?);
+                }
+
+                let is_exhaustive = match_expr
+                    .arms
+                    .iter()
+                    .any(|arm| arm.guard.is_none() && arm.pattern.is_irrefutable());
+                if !is_exhaustive {
+                    self.warnings.push(Self::span_error(
+                        &match_expr.keyword,
+                        TypeCheckerError::NonExhaustiveMatch,
+                    ));
+                }
+
+                match arm_types.split_first() {
+                    Some((first, rest)) if rest.iter().all(|arm_type| arm_type == first) => {
+                        Ok(*first)
+                    }
+                    _ => Ok(Type::Any),
+                }
+            }
+            Expr::Call(call_expr) => {
+                let mut argument_types: Vec<(Option<String>, Type)> = vec![];
+                for argument in &call_expr.arguments {
+                    let name = argument.name.as_ref().and_then(|name| name.lexeme.clone());
+                    argument_types.push((name, self.infer(&argument.value)?));
+                }
+                self.infer(&call_expr.callee)?;
+
+                let signature = match &call_expr.callee {
+                    Expr::Variable(variable_expr) => {
+                        let identifier = variable_expr.name.lexeme.clone().unwrap_or_default();
+                        self.lookup(&identifier)
+                            .and_then(|binding| binding.signature.clone())
+                    }
+                    _ => None,
+                };
+
+                let Some(signature) = signature.filter(Signature::is_annotated) else {
+                    return Ok(Type::Any);
+                };
+
+                let positional = argument_types
+                    .iter()
+                    .filter(|(name, _)| name.is_none())
+                    .count();
+                if (!signature.variadic && positional > signature.params.len())
+                    || (positional == argument_types.len() && positional < signature.required)
+                {
+                    return Err(Self::span_error(
+                        &call_expr.paren,
+                        signature.arity_error(positional),
+                    ));
+                }
+
+                let mut provided = vec![false; signature.params.len()];
+                let mut position = 0;
+                for (name, argument_type) in argument_types {
+                    let index = match name {
+                        Some(name) => {
+                            let Some(index) = signature
+                                .params
+                                .iter()
+                                .position(|(param_name, _)| *param_name == name)
+                            else {
+                                return Err(Self::span_error(
+                                    &call_expr.paren,
+                                    TypeCheckerError::UnknownParameter(name),
+                                ));
+                            };
+                            if provided[index] {
+                                return Err(Self::span_error(
+                                    &call_expr.paren,
+                                    TypeCheckerError::DuplicateArgument(name),
+                                ));
+                            }
+                            index
+                        }
+                        None => {
+                            position += 1;
+                            position - 1
+                        }
+                    };
+                    let Some((name, type_annotation)) = signature.params.get(index) else {
+                        continue;
+                    };
+                    provided[index] = true;
+
+                    if let Some(expected_type) = type_annotation {
+                        if !expected_type.accepts(&argument_type) {
+                            return Err(Self::span_error(
+                                &call_expr.paren,
+                                TypeCheckerError::MismatchedArgument(
+                                    name.clone(),
+                                    expected_type.to_string(),
+                                    argument_type.to_string(),
+                                ),
+                            ));
+                        }
+                    }
+                }
+
+                if let Some(index) = provided[..signature.required]
+                    .iter()
+                    .position(|provided| !provided)
+                {
+                    return Err(Self::span_error(
+                        &call_expr.paren,
+                        TypeCheckerError::MissingArgument(signature.params[index].0.clone()),
+                    ));
+                }
+
+                Ok(signature.return_type.unwrap_or(Type::Any))
+            }
+            Expr::Partial(partial_expr) => {
+                self.infer(&partial_expr.callee)?;
+                for argument in &partial_expr.arguments {
+                    if !argument.is_placeholder() {
+                        self.infer(&argument.value)?;
+                    }
+                }
+                Ok(Type::Function)
+            }
+            Expr::OptionalChain(optional_chain_expr) => {
+                self.infer(&optional_chain_expr.expression)?;
+                Ok(Type::Any)
+            }
+            Expr::Conditional(conditional_expr) => {
+                self.infer(&conditional_expr.condition)?;
+                let then_type = self.infer(&conditional_expr.then_branch)?;
+                let else_type = self.infer(&conditional_expr.else_branch)?;
+                if then_type == else_type {
+                    Ok(then_type)
+                } else {
+                    Ok(Type::Any)
+                }
+            }
+            Expr::Block(block_expr) => {
+                self.check_statement(&block_expr.body)?;
+                Ok(Type::Any)
+            }
+            Expr::Get(get_expr) => {
+                self.infer(&get_expr.object)?;
+                Ok(Type::Any)
+            }
+            Expr::Interpolation(interpolation_expr) => {
+                for part in &interpolation_expr.parts {
+                    self.infer(part)?;
+                }
+                Ok(Type::String)
+            }
+        }
+    }
+
+    fn check_statement(&mut self, statement: &Stmt) -> Result<(), ErrorCascade<TypeCheckerError>> {
+        match statement {
+            Stmt::Expression(expression_statement) => {
+                self.infer(&expression_statement.expression)?;
+            }
+            Stmt::Print(print_statement) => {
+                self.infer(&print_statement.expression)?;
+            }
+            Stmt::Value(value_statement) => {
+                self.tail = Tail::value(self.infer(&value_statement.expression)?);
+                return Ok(());
+            }
+            Stmt::Variable(variable_statement) => {
+                let identifier = variable_statement.name.lexeme.clone().unwrap_or_default();
+                let value_type = match &variable_statement.initializer {
+                    Some(initializer) => Some(self.infer(initializer)?),
+                    None => None,
+                };
+                let signature = match &variable_statement.initializer {
+                    Some(Expr::Variable(variable_expr)) => {
+                        let source = variable_expr.name.lexeme.clone().unwrap_or_default();
+                        self.lookup(&source)
+                            .and_then(|binding| binding.signature.clone())
+                    }
+                    _ => None,
+                };
+
+                let binding = match (variable_statement.type_annotation, value_type) {
+                    (Some(expected_type), Some(value_type)) => {
+                        if !expected_type.accepts(&value_type) {
+                            return Err(Self::span_error(
+                                &variable_statement.name,
+                                TypeCheckerError::MismatchedType(
+                                    expected_type.to_string(),
+                                    value_type.to_string(),
+                                ),
+                            ));
+                        }
+                        Binding {
+                            value_type: expected_type,
+                            is_annotated: true,
+                            signature,
+                        }
+                    }
+                    (Some(expected_type), None) => Binding {
+                        value_type: expected_type,
+                        is_annotated: true,
+                        signature: None,
+                    },
+                    (None, value_type) => Binding {
+                        value_type: value_type.unwrap_or(Type::Null),
+                        is_annotated: false,
+                        signature,
+                    },
+                };
+                self.declare(&identifier, binding);
+            }
+            Stmt::Destructure(destructure_statement) => {
+                self.infer(&destructure_statement.initializer)?;
+                for name in destructure_statement.pattern.names() {
+                    self.declare(
+                        &name,
+                        Binding {
+                            value_type: Type::Any,
+                            is_annotated: false,
+                            signature: None,
+                        },
+                    );
+                }
+            }
+            Stmt::Block(block_statement) => {
+                self.scopes.push(HashMap::new());
+                let mut tail = Tail::MISSING;
+                let result = block_statement.statements.iter().try_for_each(|statement| {
+                    self.check_statement(statement)?;
+                    if !tail.diverges() {
+                        tail = self.tail;
+                    }
+                    Ok(())
+                });
+                self.scopes.pop();
+                result?;
+                self.tail = tail;
+                return Ok(());
+            }
+            Stmt::If(if_statement) => {
+                self.infer(&if_statement.condition)?;
+                self.check_statement(&if_statement.then_branch)?;
+                let then_tail = self.tail;
+                let else_tail = match &if_statement.else_branch {
+                    Some(else_branch) => {
+                        self.check_statement(else_branch)?;
+                        self.tail
+                    }
+                    None => Tail::MISSING,
+                };
+                self.tail = then_tail.join(else_tail);
+                return Ok(());
+            }
+            Stmt::While(while_statement) => {
+                self.infer(&while_statement.condition)?;
+                self.check_statement(&while_statement.body)?;
+                if let Some(increment) = &while_statement.increment {
+                    self.infer(increment)?;
+                }
+            }
+            Stmt::Function(function_statement) => {
+                let identifier = function_statement.name.lexeme.clone().unwrap_or_default();
+                let fixed: Vec<&Parameter> = function_statement
+                    .params
+                    .iter()
+                    .filter(|param| !param.variadic)
+                    .collect();
+                let signature = Signature {
+                    params: fixed
+                        .iter()
+                        .map(|param| (param.label(), param.type_annotation))
+                        .collect(),
+                    required: fixed.iter().filter(|param| param.default.is_none()).count(),
+                    variadic: fixed.len() < function_statement.params.len(),
+                    return_type: function_statement.return_type,
+                };
+                self.declare(
+                    &identifier,
+                    Binding {
+                        value_type: Type::Function,
+                        is_annotated: false,
+                        signature: Some(signature.clone()),
+                    },
+                );
+
+                let mut parameters: HashMap<String, Binding> = HashMap::new();
+                for param in &function_statement.params {
+                    let names = match &param.pattern {
+                        Some(pattern) => pattern.names(),
+                        None => vec![param.name.lexeme.clone().unwrap_or_default()],
+                    };
+                    let value_type = match param.variadic {
+                        true => Type::List,
+                        false => param.type_annotation.unwrap_or(Type::Any),
+                    };
+                    for name in names {
+                        parameters.insert(
+                            name,
+                            Binding {
+                                value_type,
+                                is_annotated: param.type_annotation.is_some(),
+                                signature: None,
+                            },
+                        );
+                    }
+                }
+
+                self.scopes.push(parameters);
+                for param in &function_statement.params {
+                    let Some(default) = &param.default else {
+                        continue;
+                    };
+                    let default_type = self.infer(default);
+                    match (default_type, param.type_annotation) {
+                        (Ok(default_type), Some(expected_type))
+                            if !expected_type.accepts(&default_type) =>
+                        {
+                            self.scopes.pop();
+                            return Err(Self::span_error(
+                                &param.name,
+                                TypeCheckerError::MismatchedArgument(
+                                    param.label(),
+                                    expected_type.to_string(),
+                                    default_type.to_string(),
+                                ),
+                            ));
+                        }
+                        (Err(error), _) => {
+                            self.scopes.pop();
+                            return Err(error);
+                        }
+                        _ => {}
+                    }
+                }
+                self.return_types.push(function_statement.return_type);
+                let result = self.check_statement(&function_statement.body);
+                self.return_types.pop();
+                self.scopes.pop();
+                result?;
+
+                if let Some(expected_type) = function_statement.return_type {
+                    if let Some(value_type) = self.tail.value_type {
+                        if !expected_type.accepts(&value_type) {
+                            return Err(Self::span_error(
+                                &function_statement.name,
+                                TypeCheckerError::MismatchedReturn(
+                                    expected_type.to_string(),
+                                    value_type.to_string(),
+                                ),
+                            ));
+                        }
+                    }
+                    if self.tail.is_missing && !expected_type.accepts(&Type::Null) {
+                        self.warnings.push(Self::span_error(
+                            &function_statement.name,
+                            TypeCheckerError::MissingReturn(expected_type.to_string()),
+                        ));
+                    }
+                }
+            }
+            Stmt::Return(return_statement) => {
+                let value_type = match &return_statement.value {
+                    Some(value) => self.infer(value)?,
+                    None => Type::Null,
+                };
+                if let Some(Some(expected_type)) = self.return_types.last() {
+                    if !expected_type.accepts(&value_type) {
+                        return Err(Self::span_error(
+                            &return_statement.keyword,
+                            TypeCheckerError::MismatchedReturn(
+                                expected_type.to_string(),
+                                value_type.to_string(),
+                            ),
+                        ));
+                    }
+                }
+                self.tail = Tail::DIVERGES;
+                return Ok(());
+            }
+            Stmt::ForIn(for_in_statement) => {
+                let item_type = match self.infer(&for_in_statement.iterable)? {
+                    Type::String => Type::String,
+                    Type::Range => Type::Integer,
+                    _ => Type::Any,
+                };
+                let identifier = for_in_statement.name.lexeme.clone().unwrap_or_default();
+
+                self.scopes.push(HashMap::from([(
+                    identifier,
+                    Binding {
+                        value_type: item_type,
+                        is_annotated: false,
+                        signature: None,
+                    },
+                )]));
+                let result = self.check_statement(&for_in_statement.body);
+                self.scopes.pop();
+                result?;
+            }
+            Stmt::Break(_) | Stmt::Continue(_) => {
+                self.tail = Tail::DIVERGES;
+                return Ok(());
+            }
+            Stmt::Test(test_statement) => {
+                self.check_statement(&test_statement.body)?;
+            }
+            Stmt::Import(import_statement) => {
+                let alias = import_statement.alias.lexeme.clone().unwrap_or_default();
+                self.declare(
+                    &alias,
+                    Binding {
+                        value_type: Type::Module,
+                        is_annotated: false,
+                        signature: None,
+                    },
+                );
+            }
+            Stmt::Export(export_statement) => {
+                self.check_statement(&export_statement.declaration)?;
+            }
+        }
+        self.tail = Tail::MISSING;
+        Ok(())
+    }
+
+    pub fn check(&mut self, statements: &[Stmt]) -> Result<(), ErrorCascade<TypeCheckerError>> {
+        for statement in statements {
+            self.check_statement(statement)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod checker_tests {
+    use crate::{lexer::Lexer, parser::Parser};
+
+    use super::*;
+
+    fn check(source: &str) -> Result<(), ErrorCascade<TypeCheckerError>> {
+        let mut lexer = Lexer::new();
+        let tokens = lexer.tokenize(source).unwrap();
+        let mut parser = Parser::new();
+        let statements = parser.parse(tokens).unwrap();
+        TypeChecker::new().check(&statements)
+    }
+
+    #[test]
+    fn accepts_matching_annotations() {
+        let checker_result = check(
+            "
+            let count: integer = 1;
+            fn add(a: number, b: number) -> number {
+                return a + b;
+            }
+            let total: number = add(count, 2.5);
+            ",
+        );
+        assert!(checker_result.is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_annotations() {
+        let checker_result = check("let count: integer = \"one\";");
+        assert_eq!(
+            checker_result.unwrap_err().to_string(),
+            "[line 1:5 - MismatchedType] expected a value of type `integer` but found `string`"
+        );
+    }
+
+    #[test]
+    fn leaves_unannotated_code_dynamic() {
+        let checker_result = check(
+            "
+            let value = 1;
+            value = \"text\";
+            fn apply(a, b) {
+                return a - b;
+            }
+            apply(value, true);
+            ",
+        );
+        assert!(checker_result.is_ok());
+    }
+
+    #[test]
+    fn warns_about_non_exhaustive_matches() {
+        let mut lexer = Lexer::new();
+        let tokens = lexer
+            .tokenize(
+                "let a = match 1 { 1 => 2 }; let b = match 1 { 1 => 2, n if n > 1 => 3, _ => 4 };",
+            )
+            .unwrap();
+        let mut parser = Parser::new();
+        let statements = parser.parse(tokens).unwrap();
+
+        let mut checker = TypeChecker::new();
+        assert!(checker.check(&statements).is_ok());
+        let warnings: Vec<String> = checker
+            .warnings()
+            .iter()
+            .map(|warning| warning.to_string())
+            .collect();
+        assert_eq!(
+            warnings,
+            vec!["[line 1:9 - NonExhaustiveMatch] match has no wildcard or binding arm, unmatched values will fail at runtime"]
+        );
+    }
+}
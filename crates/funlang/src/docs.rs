@@ -0,0 +1,128 @@
+use crate::{
+    ast::{
+        expr::Expr,
+        stmt::{FunctionStmt, Stmt},
+    },
+    literal::LiteralData,
+    token::Span,
+};
+
+#[derive(Debug, Clone)]
+pub struct FunctionDoc {
+    pub name: String,
+    pub signature: String,
+    pub doc: String,
+    pub exported: bool,
+    pub span: Option<Span>,
+}
+
+fn signature(function_statement: &FunctionStmt) -> String {
+    let params: Vec<String> = function_statement
+        .params
+        .iter()
+        .map(|param| {
+            let name = match param.type_annotation {
+                Some(type_annotation) => format!("{}: {}", param.label(), type_annotation),
+                None => param.label(),
+            };
+            match &param.default {
+                Some(Expr::Literal(literal_expr)) => match &literal_expr.literal {
+                    LiteralData::String(string_value) => format!("{} = \"{}\"", name, string_value),
+                    literal => format!("{} = {}", name, literal),
+                },
+                Some(_) => format!("{} = ...", name),
+                None => name,
+            }
+        })
+        .collect();
+    let name = function_statement.name.lexeme.clone().unwrap_or_default();
+
+    match function_statement.return_type {
+        Some(return_type) => format!("fn {}({}) -> {}", name, params.join(", "), return_type),
+        None => format!("fn {}({})", name, params.join(", ")),
+    }
+}
+
+fn function_doc(function_statement: &FunctionStmt, exported: bool) -> Option<FunctionDoc> {
+    Some(FunctionDoc {
+        name: function_statement.name.lexeme.clone()?,
+        signature: signature(function_statement),
+        doc: function_statement.doc.clone()?,
+        exported,
+        span: function_statement.name.span.clone(),
+    })
+}
+
+pub fn documented_functions(statements: &[Stmt]) -> Vec<FunctionDoc> {
+    statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Stmt::Function(function_statement) => function_doc(function_statement, false),
+            Stmt::Export(export_statement) => match &export_statement.declaration {
+                Stmt::Function(function_statement) => function_doc(function_statement, true),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod docs_tests {
+    use crate::{lexer::Lexer, parser::Parser};
+
+    use super::*;
+
+    #[test]
+    fn lists_documented_functions() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            /// Adds two numbers.
+            /// Both arguments must be numeric.
+            export fn add(a: number, b: number) -> number {
+                return a + b;
+            }
+
+            /* not a doc comment */
+            fn undocumented() {}
+
+            /// Greets someone.
+            fn greet(name, greeting = \"hi\", ...rest) {
+                print name;
+            }
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let docs: Vec<(String, String, bool)> = documented_functions(&parser_result.unwrap())
+            .into_iter()
+            .map(|function_doc| {
+                (
+                    function_doc.signature,
+                    function_doc.doc,
+                    function_doc.exported,
+                )
+            })
+            .collect();
+        assert_eq!(
+            docs,
+            vec![
+                (
+                    "fn add(a: number, b: number) -> number".to_string(),
+                    "Adds two numbers.\nBoth arguments must be numeric.".to_string(),
+                    true
+                ),
+                (
+                    "fn greet(name, greeting = \"hi\", ...rest)".to_string(),
+                    "Greets someone.".to_string(),
+                    false
+                ),
+            ]
+        );
+    }
+}
@@ -1,25 +1,47 @@
 use std::{
     cell::RefCell,
     collections::HashMap,
+    mem,
     ops::Deref,
     rc::{Rc, Weak},
 };
 
 use funlang_error::ErrorCascade;
 
-use crate::{ast::expr::Expr, error, errors::EnvironmentError};
+use crate::{
+    ast::expr::Expr, error, errors::EnvironmentError, functions::CallDepth, modules::ModuleLoader,
+    output::Output,
+};
 
 #[derive(Debug)]
 pub struct EnvironmentNode {
     variables: HashMap<String, Expr>,
-    parent_scope: Weak<RefCell<EnvironmentNode>>,
+    parent_scope: Option<Rc<RefCell<EnvironmentNode>>>,
+    output: Output,
+    strict: bool,
+    loader: ModuleLoader,
+    call_depth: CallDepth,
+    captured_scopes: CapturedScopes,
+    module_location: Option<String>,
+}
+
+impl Default for EnvironmentNode {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EnvironmentNode {
     pub fn new() -> Self {
         Self {
             variables: HashMap::new(),
-            parent_scope: Weak::new(),
+            parent_scope: None,
+            output: Output::default(),
+            strict: false,
+            loader: ModuleLoader::default(),
+            call_depth: CallDepth::default(),
+            captured_scopes: CapturedScopes::default(),
+            module_location: None,
         }
     }
 
@@ -33,7 +55,7 @@ impl EnvironmentNode {
         value: Expr,
     ) -> Result<(), ErrorCascade<EnvironmentError>> {
         let local_variable = self.variables.get_mut(name);
-        let parent_scope = self.parent_scope.upgrade();
+        let parent_scope = self.parent_scope.as_ref();
 
         match local_variable {
             Some(mutable_variable) => {
@@ -49,7 +71,7 @@ impl EnvironmentNode {
 
     pub fn variable(&self, name: &str) -> Option<Expr> {
         let local_variable = self.variables.get(name).cloned();
-        let parent_scope = self.parent_scope.upgrade();
+        let parent_scope = self.parent_scope.as_ref();
 
         local_variable.or(match parent_scope {
             Some(parent_scope) => parent_scope.deref().borrow().variable(name),
@@ -58,9 +80,18 @@ impl EnvironmentNode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
+pub struct CapturedScopes(Rc<RefCell<Vec<Weak<RefCell<EnvironmentNode>>>>>);
+
+#[derive(Debug, Clone)]
 pub struct Environment(pub Rc<RefCell<EnvironmentNode>>);
 
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Environment {
     pub fn new() -> Self {
         Self(Rc::new(RefCell::new(EnvironmentNode::new())))
@@ -68,10 +99,26 @@ impl Environment {
 
     pub fn create_scope(&mut self) -> Self {
         let new_environment = Self::new();
-        new_environment.0.borrow_mut().parent_scope = Rc::downgrade(&self.0);
+        {
+            let mut new_node = new_environment.0.borrow_mut();
+            new_node.parent_scope = Some(self.0.clone());
+            new_node.output = self.output();
+            new_node.strict = self.is_strict();
+            new_node.loader = self.loader();
+            new_node.call_depth = self.call_depth();
+            new_node.captured_scopes = self.0.borrow().captured_scopes.clone();
+            new_node.module_location = self.module_location();
+        }
         new_environment
     }
 
+    pub fn root(&self) -> Self {
+        match &self.0.borrow().parent_scope {
+            Some(parent_scope) => Self(parent_scope.clone()).root(),
+            None => self.clone(),
+        }
+    }
+
     pub fn define(&mut self, name: &str, value: Expr) {
         self.0.borrow_mut().define(name, value);
     }
@@ -87,6 +134,69 @@ impl Environment {
     pub fn variable(&self, name: &str) -> Option<Expr> {
         self.0.borrow().variable(name)
     }
+
+    pub fn output(&self) -> Output {
+        self.0.borrow().output.clone()
+    }
+
+    pub fn set_output(&mut self, output: Output) {
+        self.0.borrow_mut().output = output;
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.0.borrow().strict
+    }
+
+    pub fn set_strict(&mut self, strict: bool) {
+        self.0.borrow_mut().strict = strict;
+    }
+
+    pub fn loader(&self) -> ModuleLoader {
+        self.0.borrow().loader.clone()
+    }
+
+    pub fn set_loader(&mut self, loader: ModuleLoader) {
+        self.0.borrow_mut().loader = loader;
+    }
+
+    pub fn capture(&self) {
+        let captured_scopes = self.0.borrow().captured_scopes.clone();
+        let mut scopes = captured_scopes.0.borrow_mut();
+        let scope = Rc::downgrade(&self.0);
+        if scopes.last().is_some_and(|last| last.ptr_eq(&scope)) {
+            return;
+        }
+        if scopes.len() == scopes.capacity() {
+            scopes.retain(|scope| scope.strong_count() > 0);
+        }
+        scopes.push(scope);
+    }
+
+    pub fn release(&self) {
+        let captured_scopes = self.0.borrow().captured_scopes.clone();
+        let scopes = mem::take(&mut *captured_scopes.0.borrow_mut());
+        for scope in scopes.iter().filter_map(Weak::upgrade) {
+            let variables = mem::take(&mut scope.borrow_mut().variables);
+            drop(variables);
+        }
+        self.loader().clear();
+    }
+
+    pub fn module_location(&self) -> Option<String> {
+        self.0.borrow().module_location.clone()
+    }
+
+    pub fn set_module_location(&mut self, module_location: Option<String>) {
+        self.0.borrow_mut().module_location = module_location;
+    }
+
+    pub fn call_depth(&self) -> CallDepth {
+        self.0.borrow().call_depth.clone()
+    }
+
+    pub fn set_call_depth(&mut self, call_depth: CallDepth) {
+        self.0.borrow_mut().call_depth = call_depth;
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 use funlang_derive::Error;
 
+use crate::{functions::PendingCall, literal::LiteralData};
+
 #[derive(Error)]
 pub enum EnvironmentError {
     #[message = "variable being fetched does not exist"]
-    InvalidVariable
+    InvalidVariable,
 }
 
 #[derive(Error)]
@@ -18,6 +20,44 @@ pub enum InterpreterError {
     InvalidBinaryOperator(String),
     #[message = "`{}` is not a valid unary operator"]
     InvalidUnaryOperator(String),
+    #[message = "operator `{}` can not be applied to `{}` and `{}`"]
+    TypeMismatch(String, String, String),
+    #[message = "operator `{}` can not be applied to `{}`"]
+    InvalidOperandType(String, String),
+    #[message = "`{}` and `{}` can not be ordered"]
+    UnorderedValues(String, String),
+    #[message = "can not return `{}` from outside of a function"]
+    Return(LiteralData),
+    #[message = "tail call to `{}` escaped its function"]
+    TailCall(PendingCall),
+    #[message = "optional chain short-circuited outside of its chain"]
+    ShortCircuit,
+    #[message = "maximum call depth of {} exceeded"]
+    CallDepthExceeded(usize),
+    #[message = "`break` target `{}` is not an enclosing loop"]
+    Break(String),
+    #[message = "`continue` target `{}` is not an enclosing loop"]
+    Continue(String),
+    #[message = "range bounds must be integers but found `{}`"]
+    InvalidRangeBound(String),
+    #[message = "values of type `{}` can not be indexed"]
+    InvalidIndexTarget(String),
+    #[message = "values of type `{}` can not be used as an index"]
+    InvalidIndex(String),
+    #[message = "index `{}` is out of bounds for length {}"]
+    IndexOutOfBounds(String, usize),
+    #[message = "can not destructure `{}` from a value of type `{}`"]
+    InvalidDestructure(String, String),
+    #[message = "expected {} values to destructure but found {}"]
+    DestructureLength(usize, usize),
+    #[message = "no match arm matched `{}`"]
+    NoMatchingArm(String),
+    #[message = "values of type `{}` can not be iterated"]
+    NotIterable(String),
+    #[message = "script failed type checking"]
+    TypeCheckException,
+    #[message = "test setup failed: {}"]
+    TestSetupException(String),
     #[message = "Expression could not be identified"]
     UnexpectedExpression,
     #[message = "something went wrong during addition"]
@@ -28,6 +68,18 @@ pub enum InterpreterError {
     DivisionException,
     #[message = "something went wrong during multiplication"]
     MultiplicationException,
+    #[message = "something went wrong during modulo"]
+    ModuloException,
+    #[message = "something went wrong during integer division"]
+    IntegerDivisionException,
+    #[message = "something went wrong during exponentiation"]
+    ExponentiationException,
+    #[message = "can not shift by `{}` bits"]
+    InvalidShiftAmount(String),
+    #[message = "integer operation overflowed"]
+    IntegerOverflow,
+    #[message = "attempted to divide an integer by zero"]
+    DivisionByZero,
     #[message = "`(` grouping was not closed"]
     UnterminatedGrouping,
     #[message = "literal data could not be identified"]
@@ -42,6 +94,41 @@ pub enum InterpreterError {
     InvalidIdentifier(String),
     #[message = "Expected {} arguments but got {}"]
     InvalidArguments(u32, u32),
+    #[message = "Expected {} to {} arguments but got {}"]
+    InvalidArgumentRange(u32, u32, u32),
+    #[message = "Expected at least {} arguments but got {}"]
+    TooFewArguments(u32, u32),
+    #[message = "missing a value for parameter `{}`"]
+    MissingArgument(String),
+    #[message = "function does not have a parameter named `{}`"]
+    UnknownParameter(String),
+    #[message = "parameter `{}` was given more than one value"]
+    DuplicateArgument(String),
+    #[message = "failed to write to the interpreter output"]
+    OutputException,
+    #[message = "assertion failed"]
+    AssertionFailed,
+    #[message = "assertion failed: `{}` is not equal to `{}`"]
+    AssertionNotEqual(String, String),
+    #[message = "could not resolve module `{}`: {}"]
+    UnresolvedModule(String, String),
+    #[message = "something went wrong while loading module `{}`"]
+    ModuleException(String),
+    #[message = "import cycle detected: {}"]
+    CyclicImport(String),
+    #[message = "module `{}` does not export `{}`"]
+    UndefinedExport(String, String),
+    #[message = "values of type `{}` do not have properties"]
+    InvalidPropertyAccess(String),
+}
+
+impl InterpreterError {
+    pub fn is_control_flow(&self) -> bool {
+        matches!(
+            self,
+            Self::Return(_) | Self::TailCall(_) | Self::Break(_) | Self::Continue(_)
+        )
+    }
 }
 
 #[derive(Error)]
@@ -54,6 +141,16 @@ pub enum LexerError {
     InvalidCharacterIndex,
     #[message = "string literal was not closed"]
     UnterminatedString,
+    #[message = "integer literal `{}` does not fit in 64 bits"]
+    IntegerOutOfRange(String),
+    #[message = "invalid escape sequence `{}`"]
+    InvalidEscape(String),
+    #[message = "invalid unicode escape `{}`"]
+    InvalidUnicodeEscape(String),
+    #[message = "interpolated expression was not closed by `}`"]
+    UnterminatedInterpolation,
+    #[message = "block comment was not closed by `*/`"]
+    UnterminatedComment,
 }
 
 #[derive(Error)]
@@ -64,6 +161,8 @@ pub enum ParserError {
     InvalidTokenIndex,
     #[message = "invalid number"]
     InvalidNumber,
+    #[message = "integer literal `{}` does not fit in 64 bits"]
+    IntegerOutOfRange(String),
     #[message = "attempted to negate a boolean"]
     NegatedBoolean,
     #[message = "literal data could not be identified"]
@@ -92,6 +191,12 @@ pub enum ParserError {
     ExpectedForBlock,
     #[message = "expected a block statement `{/* block */}` after the function identifier"]
     ExpectedFunctionBlock,
+    #[message = "expected a block statement `{/* block */}` after the test name"]
+    ExpectedTestBlock,
+    #[message = "expected a string name after `test`"]
+    ExpectedTestName,
+    #[message = "`test` can only be declared at the top level of the entry program"]
+    NestedTest,
     #[message = "expected a parenthesized `()` argument section"]
     ExpectedArguments,
     #[message = "expected an identifier name"]
@@ -104,10 +209,86 @@ pub enum ParserError {
     InvalidFunction,
     #[message = "a function can't have more than 255 arguments"]
     MaxArguments,
+    #[message = "expected a block `{/* arms */}` after the match subject"]
+    ExpectedMatchBlock,
+    #[message = "expected a literal, range, binding or `_` pattern"]
+    ExpectedPattern,
+    #[message = "expected `=>` after the match pattern"]
+    ExpectedFatArrow,
+    #[message = "match arms should be separated by `,` and terminated by `}`"]
+    UnterminatedMatch,
+    #[message = "range pattern bounds must be integers"]
+    InvalidRangePattern,
+    #[message = "expected `:` between the branches of a conditional expression"]
+    ExpectedConditionalColon,
+    #[message = "rest parameter `{}` must be the last parameter"]
+    RestParameterNotLast(String),
+    #[message = "parameter `{}` needs a default value because an earlier parameter has one"]
+    MissingDefault(String),
+    #[message = "positional arguments can not follow named arguments"]
+    PositionalAfterNamed,
+    #[message = "expected a name in the destructuring pattern"]
+    ExpectedDestructureName,
+    #[message = "destructuring pattern was not closed by `{}`"]
+    UnterminatedDestructure(String),
+    #[message = "expected a `]` after the index"]
+    UnterminatedIndex,
+    #[message = "expected `in` after the loop variable"]
+    ExpectedIn,
     #[message = "expected a `;` after the loop condition"]
     ExpectedLoopConditionTermination,
     #[message = "expression before the `=` symbol should be a valid variable"]
     InvalidAssignmentTarget,
     #[message = "variable with identifier `{}` does not exist"]
     InvalidIdentifier(String),
+    #[message = "expected a type name after `:` or `->`"]
+    ExpectedType,
+    #[message = "`{}` is not a known type"]
+    UnknownType(String),
+    #[message = "`return` can only be used inside a function"]
+    ReturnOutsideFunction,
+    #[message = "`{}` can only be used inside a loop"]
+    LoopControlOutsideLoop(String),
+    #[message = "no enclosing loop is labelled `{}`"]
+    UndefinedLabel(String),
+    #[message = "expected a `while` or `for` loop after a label"]
+    ExpectedLabelledLoop,
+    #[message = "expected a `;` after `{}`"]
+    UnterminatedLoopControl(String),
+    #[message = "expected a module path string after `import`"]
+    ExpectedModulePath,
+    #[message = "expected `as` and a module name after the module path"]
+    ExpectedModuleAlias,
+    #[message = "expected a property name after `.`"]
+    ExpectedPropertyName,
+    #[message = "only top-level `let` and `fn` declarations can be exported"]
+    InvalidExport,
+    #[message = "interpolated expression was not closed by `}`"]
+    UnterminatedInterpolation,
+}
+
+#[derive(Error)]
+pub enum TypeCheckerError {
+    #[message = "expected a value of type `{}` but found `{}`"]
+    MismatchedType(String, String),
+    #[message = "Expected {} arguments but got {}"]
+    InvalidArguments(u32, u32),
+    #[message = "Expected {} to {} arguments but got {}"]
+    InvalidArgumentRange(u32, u32, u32),
+    #[message = "Expected at least {} arguments but got {}"]
+    TooFewArguments(u32, u32),
+    #[message = "missing a value for parameter `{}`"]
+    MissingArgument(String),
+    #[message = "function does not have a parameter named `{}`"]
+    UnknownParameter(String),
+    #[message = "parameter `{}` was given more than one value"]
+    DuplicateArgument(String),
+    #[message = "parameter `{}` expects a value of type `{}` but found `{}`"]
+    MismatchedArgument(String, String, String),
+    #[message = "function should return `{}` but returns `{}`"]
+    MismatchedReturn(String, String),
+    #[message = "function should return `{}` but can reach the end of its body without returning"]
+    MissingReturn(String),
+    #[message = "match has no wildcard or binding arm, unmatched values will fail at runtime"]
+    NonExhaustiveMatch,
 }
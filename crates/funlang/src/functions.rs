@@ -1,20 +1,65 @@
-use std::fmt::{Debug, Display};
+use std::{
+    cell::Cell,
+    fmt::{Debug, Display},
+    rc::Rc,
+};
 
 use funlang_error::ErrorCascade;
 
 use crate::{
-    ast::{
-        expr::{Expr, LiteralExpr},
-        stmt::FunctionStmt,
-        traits::Executable,
-    },
+    ast::{stmt::FunctionStmt, traits::Evaluable},
     environment::Environment,
     error,
     errors::InterpreterError,
     literal::LiteralData,
-    token::Token,
 };
 
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1000;
+
+const STACK_RED_ZONE: usize = 256 * 1024;
+const STACK_SEGMENT_SIZE: usize = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct CallDepth {
+    depth: Rc<Cell<usize>>,
+    limit: usize,
+}
+
+impl Default for CallDepth {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CALL_DEPTH)
+    }
+}
+
+impl CallDepth {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            depth: Rc::new(Cell::new(0)),
+            limit,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth.get()
+    }
+
+    fn enter(&self) -> Result<CallGuard, ErrorCascade<InterpreterError>> {
+        if self.depth.get() >= self.limit {
+            return Err(error!(InterpreterError::CallDepthExceeded(self.limit)));
+        }
+        self.depth.set(self.depth.get() + 1);
+        Ok(CallGuard(self.depth.clone()))
+    }
+}
+
+struct CallGuard(Rc<Cell<usize>>);
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
 pub trait Callable {
     fn call(
         &self,
@@ -23,15 +68,21 @@ pub trait Callable {
     ) -> Result<LiteralData, ErrorCascade<InterpreterError>>;
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Function {
     arity: u32,
-    declaration: Box<FunctionStmt>,
+    declaration: Rc<FunctionStmt>,
+    closure: Environment,
 }
 
 impl Function {
-    pub fn new(arity: u32, declaration: Box<FunctionStmt>) -> Self {
-        Self { arity, declaration }
+    pub fn new(arity: u32, declaration: Rc<FunctionStmt>, closure: Environment) -> Self {
+        closure.capture();
+        Self {
+            arity,
+            declaration,
+            closure,
+        }
     }
 
     pub fn arity(&self) -> u32 {
@@ -41,51 +92,331 @@ impl Function {
     pub fn declaration(&self) -> &FunctionStmt {
         &self.declaration
     }
+
+    pub fn name(&self) -> &str {
+        self.declaration.name.lexeme.as_deref().unwrap_or_default()
+    }
 }
 
 impl PartialEq for Function {
     fn eq(&self, other: &Self) -> bool {
-        format!("{:?}", self) == format!("{:?}", other)
+        Rc::ptr_eq(&self.declaration, &other.declaration)
+            && Rc::ptr_eq(&self.closure.0, &other.closure.0)
     }
-    fn ne(&self, other: &Self) -> bool {
-        format!("{:?}", self) != format!("{:?}", other)
+}
+
+impl Debug for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Function")
+            .field("arity", &self.arity)
+            .field("declaration", &self.declaration)
+            .finish()
     }
 }
 
 impl Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "<fn {}>", self.declaration.name.lexeme.as_ref().unwrap())
+        write!(f, "<fn {}>", self.name())
     }
 }
 
-impl Callable for Function {
+pub struct PendingCall {
+    function: Function,
+    environment: Environment,
+}
+
+impl PendingCall {
+    pub fn new(function: Function, environment: Environment) -> Self {
+        Self {
+            function,
+            environment,
+        }
+    }
+}
+
+impl Display for PendingCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.function)
+    }
+}
+
+type NativeBody = dyn Fn(
+    &mut Environment,
+    Vec<LiteralData>,
+) -> Result<LiteralData, ErrorCascade<InterpreterError>>;
+
+#[derive(Clone)]
+pub struct NativeFunction {
+    name: String,
+    arity: u32,
+    variadic: bool,
+    body: Rc<NativeBody>,
+}
+
+impl NativeFunction {
+    pub fn new<F>(name: &str, arity: u32, body: F) -> Self
+    where
+        F: Fn(
+                &mut Environment,
+                Vec<LiteralData>,
+            ) -> Result<LiteralData, ErrorCascade<InterpreterError>>
+            + 'static,
+    {
+        Self {
+            name: name.to_owned(),
+            arity,
+            variadic: false,
+            body: Rc::new(body),
+        }
+    }
+
+    pub fn variadic<F>(name: &str, arity: u32, body: F) -> Self
+    where
+        F: Fn(
+                &mut Environment,
+                Vec<LiteralData>,
+            ) -> Result<LiteralData, ErrorCascade<InterpreterError>>
+            + 'static,
+    {
+        Self {
+            variadic: true,
+            ..Self::new(name, arity, body)
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn arity(&self) -> u32 {
+        self.arity
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+}
+
+impl PartialEq for NativeFunction {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.body, &other.body)
+    }
+}
+
+impl Debug for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NativeFunction")
+            .field("name", &self.name)
+            .field("arity", &self.arity)
+            .finish()
+    }
+}
+
+impl Display for NativeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+impl Callable for NativeFunction {
     fn call(
         &self,
         environment: &mut Environment,
         arguments: Vec<LiteralData>,
     ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
-        let mut environment = environment.create_scope();
-
-        let param_iter: Vec<(usize, &Token)> = self.declaration.params.iter().enumerate().collect();
-        for (index, param) in param_iter {
-            environment.define(
-                &param
-                    .lexeme
-                    .clone()
-                    .ok_or(error!(InterpreterError::MissingIdentifier))?,
-                Expr::Literal(Box::new(LiteralExpr {
-                    literal: arguments
-                        .get(index)
-                        .ok_or(error!(InterpreterError::InvalidArguments(
-                            self.arity(),
-                            self.declaration.params.len() as u32
-                        )))?
-                        .clone(),
-                })),
-            );
+        if self.variadic && (arguments.len() as u32) < self.arity {
+            return Err(error!(InterpreterError::TooFewArguments(
+                self.arity,
+                arguments.len() as u32
+            )));
+        }
+        if !self.variadic && arguments.len() as u32 != self.arity {
+            return Err(error!(InterpreterError::InvalidArguments(
+                self.arity,
+                arguments.len() as u32
+            )));
+        }
+        (self.body)(environment, arguments)
+    }
+}
+
+impl Function {
+    fn arity_error(
+        &self,
+        required: usize,
+        fixed: usize,
+        variadic: bool,
+        found: usize,
+    ) -> InterpreterError {
+        if variadic {
+            InterpreterError::TooFewArguments(required as u32, found as u32)
+        } else if required == fixed {
+            InterpreterError::InvalidArguments(fixed as u32, found as u32)
+        } else {
+            InterpreterError::InvalidArgumentRange(required as u32, fixed as u32, found as u32)
         }
+    }
+
+    pub fn bind_arguments(
+        &self,
+        arguments: Vec<LiteralData>,
+        named_arguments: Vec<(String, LiteralData)>,
+    ) -> Result<Environment, ErrorCascade<InterpreterError>> {
+        let params = &self.declaration.params;
+        let (rest, fixed) = match params.split_last() {
+            Some((last, fixed)) if last.variadic => (Some(last), fixed),
+            _ => (None, &params[..]),
+        };
+        let required = fixed.iter().filter(|param| param.default.is_none()).count();
 
-        self.declaration.body.execute(&mut environment)?;
-        Ok(LiteralData::None)
+        if (rest.is_none() && arguments.len() > fixed.len())
+            || (named_arguments.is_empty() && arguments.len() < required)
+        {
+            return Err(error!(self.arity_error(
+                required,
+                fixed.len(),
+                rest.is_some(),
+                arguments.len()
+            )));
+        }
+
+        let mut positional = arguments.into_iter();
+        let mut values: Vec<Option<LiteralData>> =
+            fixed.iter().map(|_| positional.next()).collect();
+        let extra: Vec<LiteralData> = positional.collect();
+
+        for (name, value) in named_arguments {
+            let index = fixed
+                .iter()
+                .position(|param| {
+                    param.pattern.is_none() && param.name.lexeme.as_deref() == Some(name.as_str())
+                })
+                .ok_or(error!(InterpreterError::UnknownParameter(name.clone())))?;
+            if values[index].is_some() {
+                return Err(error!(InterpreterError::DuplicateArgument(name)));
+            }
+            values[index] = Some(value);
+        }
+
+        let mut environment = self.closure.clone().create_scope();
+        for (param, value) in fixed.iter().zip(values) {
+            let value = match (value, &param.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => default.evaluate(&mut environment)?,
+                (None, None) => {
+                    return Err(error!(InterpreterError::MissingArgument(param.label())))
+                }
+            };
+            param.bind(&mut environment, value)?;
+        }
+        if let Some(rest) = rest {
+            rest.bind(&mut environment, LiteralData::List(Rc::new(extra)))?;
+        }
+
+        Ok(environment)
+    }
+
+    pub fn invoke(
+        &self,
+        environment: Environment,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let _guard = environment.call_depth().enter()?;
+        let mut function = self.clone();
+        let mut environment = environment;
+
+        stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT_SIZE, || loop {
+            match function.declaration.body.execute_tail(&mut environment) {
+                Ok(value) => return Ok(value),
+                Err(error) => match error.error_type {
+                    InterpreterError::Return(value) => return Ok(value),
+                    InterpreterError::TailCall(pending_call) => {
+                        function = pending_call.function;
+                        environment = pending_call.environment;
+                    }
+                    _ => return Err(error),
+                },
+            }
+        })
+    }
+}
+
+pub fn callable_name(value: &LiteralData) -> &str {
+    match value {
+        LiteralData::Function(function_value) => function_value.name(),
+        LiteralData::NativeFunction(function_value) => function_value.name(),
+        _ => "",
+    }
+}
+
+pub fn callable_arity(value: &LiteralData) -> Option<u32> {
+    match value {
+        LiteralData::Function(function_value) => Some(function_value.arity()),
+        LiteralData::NativeFunction(function_value) => Some(function_value.arity()),
+        _ => None,
+    }
+}
+
+pub fn call_value(
+    callee: &LiteralData,
+    environment: &mut Environment,
+    arguments: Vec<LiteralData>,
+    named_arguments: Vec<(String, LiteralData)>,
+) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+    match callee {
+        LiteralData::Function(function_value) => {
+            let call_environment = function_value.bind_arguments(arguments, named_arguments)?;
+            function_value.invoke(call_environment)
+        }
+        LiteralData::NativeFunction(function_value) => match named_arguments.first() {
+            Some((name, _)) => Err(error!(InterpreterError::UnknownParameter(name.clone()))),
+            None => function_value.call(environment, arguments),
+        },
+        _ => Err(error!(InterpreterError::UncallableExpression)),
+    }
+}
+
+pub fn compose(first: LiteralData, second: LiteralData, name: &str) -> NativeFunction {
+    NativeFunction::variadic(name, 0, move |environment, arguments| {
+        let intermediate = call_value(&first, environment, arguments, vec![])?;
+        call_value(&second, environment, vec![intermediate], vec![])
+    })
+}
+
+pub fn partial(
+    callee: LiteralData,
+    template: Vec<Option<LiteralData>>,
+    named_arguments: Vec<(String, LiteralData)>,
+) -> NativeFunction {
+    let placeholders = template.iter().filter(|value| value.is_none()).count() as u32;
+    let arity = match template.iter().all(Option::is_some) {
+        true => callable_arity(&callee)
+            .unwrap_or_default()
+            .saturating_sub(template.len() as u32),
+        false => placeholders,
+    };
+    let name = callable_name(&callee).to_owned();
+
+    NativeFunction::variadic(&name, arity, move |environment, arguments| {
+        let mut supplied = arguments.into_iter();
+        let mut values: Vec<LiteralData> = template
+            .iter()
+            .map(|value| match value {
+                Some(value) => value.clone(),
+                None => supplied.next().unwrap_or(LiteralData::None),
+            })
+            .collect();
+        values.extend(supplied);
+        call_value(&callee, environment, values, named_arguments.clone())
+    })
+}
+
+impl Callable for Function {
+    fn call(
+        &self,
+        _environment: &mut Environment,
+        arguments: Vec<LiteralData>,
+    ) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let environment = self.bind_arguments(arguments, vec![])?;
+        self.invoke(environment)
     }
 }
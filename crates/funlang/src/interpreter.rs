@@ -1,35 +1,169 @@
 use funlang_error::ErrorCascade;
 
 use crate::{
-    ast::{stmt::Stmt, traits::Executable},
+    ast::{expr::Expr, stmt::Stmt, traits::Executable},
+    builtins::define_builtins,
+    checker::TypeChecker,
     environment::Environment,
+    error,
     errors::InterpreterError,
+    functions::CallDepth,
+    literal::LiteralData,
+    modules::{ModuleLoader, ModuleResolver},
+    output::Output,
+    testing::{TestResult, TestSummary},
 };
 
+pub const STRICT_PRAGMA: &str = "use strict";
+
+pub(crate) fn type_check(statements: &[Stmt]) -> Result<(), ErrorCascade<InterpreterError>> {
+    TypeChecker::new().check(statements).map_err(|error| {
+        error!(InterpreterError::TypeCheckException).set_embedded_error(Box::new(error))
+    })
+}
+
+pub(crate) fn has_strict_pragma(statements: &[Stmt]) -> bool {
+    match statements.first() {
+        Some(Stmt::Expression(expression_statement)) => match &expression_statement.expression {
+            Expr::Literal(literal_expression) => {
+                literal_expression.literal == LiteralData::String(STRICT_PRAGMA.to_string())
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 #[derive(Debug)]
 pub struct Interpreter {
     environment: Environment,
     globals: Environment,
 }
 
+// Closures keep their defining scope alive and are usually stored in it, so
+// dropping the interpreter clears the variables of every captured scope to
+// break those cycles. Function values the host still holds afterwards can be
+// called, but the variables they captured are gone.
+impl Drop for Interpreter {
+    fn drop(&mut self) {
+        self.globals.release();
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Interpreter {
     pub fn new() -> Self {
+        let mut globals = Environment::new();
+        define_builtins(&mut globals);
         Self {
-            environment: Environment::new(),
-            globals: Environment::new(),
+            environment: globals.create_scope(),
+            globals,
         }
     }
 
+    pub fn set_output(mut self, output: Output) -> Self {
+        self.environment.set_output(output.clone());
+        self.globals.set_output(output);
+        self
+    }
+
+    pub fn output(&self) -> Output {
+        self.environment.output()
+    }
+
+    pub fn set_strict(mut self, strict: bool) -> Self {
+        self.environment.set_strict(strict);
+        self.globals.set_strict(strict);
+        self
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.environment.is_strict()
+    }
+
+    pub fn set_resolver<R: ModuleResolver + 'static>(mut self, resolver: R) -> Self {
+        let loader = ModuleLoader::new(resolver);
+        self.environment.set_loader(loader.clone());
+        self.globals.set_loader(loader);
+        self
+    }
+
+    pub fn set_max_call_depth(mut self, limit: usize) -> Self {
+        let call_depth = CallDepth::new(limit);
+        self.environment.set_call_depth(call_depth.clone());
+        self.globals.set_call_depth(call_depth);
+        self
+    }
+
     pub fn interpret(
         &mut self,
         statements: Vec<Stmt>,
     ) -> Result<(), ErrorCascade<InterpreterError>> {
+        type_check(&statements)?;
+        let is_strict = self.globals.is_strict() || has_strict_pragma(&statements);
+        self.environment.set_strict(is_strict);
         for statement in statements {
             statement.execute(&mut self.environment)?;
         }
         Ok(())
     }
 
+    pub fn test(
+        &mut self,
+        statements: Vec<Stmt>,
+    ) -> Result<TestSummary, ErrorCascade<InterpreterError>> {
+        type_check(&statements)?;
+        let is_strict = has_strict_pragma(&statements);
+        let (tests, declarations): (Vec<Stmt>, Vec<Stmt>) = statements
+            .into_iter()
+            .partition(|statement| matches!(statement, Stmt::Test(_)));
+
+        let mut setup = self.globals.create_scope();
+        if is_strict {
+            setup.set_strict(true);
+        }
+        let setup_error = declarations
+            .iter()
+            .try_for_each(|declaration| declaration.execute(&mut setup).map(|_| ()))
+            .err()
+            .map(|error| error.to_string());
+
+        let mut summary = TestSummary::new();
+
+        for test in tests {
+            let Stmt::Test(test_statement) = test else {
+                continue;
+            };
+
+            let name = test_statement
+                .name
+                .literal_data
+                .as_ref()
+                .ok_or(error!(InterpreterError::InvalidLiteralData))?
+                .to_string();
+
+            let error = match &setup_error {
+                Some(message) => Some(error!(InterpreterError::TestSetupException(
+                    message.clone()
+                ))),
+                None => test_statement.body.execute(&mut setup.create_scope()).err(),
+            };
+
+            summary.results.push(TestResult {
+                name,
+                span: test_statement.name.span.clone(),
+                error,
+            });
+        }
+
+        Ok(summary)
+    }
+
     pub fn environment(&self) -> &Environment {
         &self.environment
     }
@@ -49,8 +183,10 @@ impl Interpreter {
 
 #[cfg(test)]
 mod interpreter_tests {
+    use std::{cell::RefCell, rc::Rc};
+
     use super::*;
-    use crate::{lexer::Lexer, parser::Parser};
+    use crate::{functions::call_value, lexer::Lexer, modules::MemoryResolver, parser::Parser};
 
     #[test]
     fn interprets_expression_statements() {
@@ -225,4 +361,279 @@ mod interpreter_tests {
         let mut interpreter = Interpreter::new();
         assert!(interpreter.interpret(parser_result.unwrap()).is_ok());
     }
+
+    #[test]
+    fn prints_to_configured_output() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            let a = 1;
+            print a;
+            {
+                print \"inner\";
+            }
+            fn test(b) {
+                print a + b;
+            }
+            test(2);
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let lines = Rc::new(RefCell::new(Vec::<String>::new()));
+        let captured_lines = lines.clone();
+        let mut interpreter = Interpreter::new().set_output(Output::callback(move |line| {
+            captured_lines.borrow_mut().push(line.to_string())
+        }));
+        assert!(interpreter.interpret(parser_result.unwrap()).is_ok());
+
+        assert_eq!(*lines.borrow(), vec!["1", "inner", "3"]);
+    }
+
+    #[test]
+    fn skips_test_declarations() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            test \"never runs\" {
+                assert(false);
+            }
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let mut interpreter = Interpreter::new();
+        assert!(interpreter.interpret(parser_result.unwrap()).is_ok());
+    }
+
+    #[test]
+    fn runs_test_declarations() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            let setups = 0;
+            setups = setups + 1;
+
+            fn add(a, b) {
+                return a + b;
+            }
+
+            test \"adds numbers\" {
+                let total = add(1, 2);
+                assert_eq(total, 3);
+            }
+
+            test \"runs setup once\" {
+                assert_eq(setups, 1);
+            }
+
+            test \"reports failures\" {
+                let total = add(2, 2);
+                assert_eq(total, 5);
+            }
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let mut interpreter = Interpreter::new();
+        let summary = interpreter.test(parser_result.unwrap()).unwrap();
+
+        assert_eq!(summary.passed(), 2);
+        assert_eq!(summary.failed(), 1);
+        assert_eq!(summary.results[2].name, "reports failures");
+        assert!(summary.results[2].error.is_some());
+    }
+
+    #[test]
+    fn reports_setup_failures_per_test() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            let broken = missing;
+
+            test \"first\" {
+                assert(true);
+            }
+
+            test \"second\" {
+                assert(true);
+            }
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let mut interpreter = Interpreter::new();
+        let summary = interpreter.test(parser_result.unwrap()).unwrap();
+
+        assert_eq!(summary.failed(), 2);
+        assert!(summary.results.iter().all(|result| matches!(
+            result.error.as_ref().map(|error| &error.error_type),
+            Some(InterpreterError::TestSetupException(_))
+        )));
+    }
+
+    #[test]
+    fn rejects_mismatched_types_in_strict_mode() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize("print \"3\" - true;");
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+        let statements = parser_result.unwrap();
+
+        let mut interpreter = Interpreter::new().set_output(Output::sink());
+        assert!(interpreter.interpret(statements.clone()).is_ok());
+
+        let mut interpreter = Interpreter::new()
+            .set_output(Output::sink())
+            .set_strict(true);
+        let error = interpreter.interpret(statements).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "[line 1:11 - TypeMismatch] operator `-` can not be applied to `string` and `bool`"
+        );
+    }
+
+    #[test]
+    fn limits_call_depth() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            fn depth(n) {
+                return n == 0 ? 0 : 1 + depth(n - 1);
+            }
+            let result = depth(50);
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let mut interpreter = Interpreter::new()
+            .set_output(Output::sink())
+            .set_max_call_depth(20);
+        let error = interpreter.interpret(parser_result.unwrap()).unwrap_err();
+        assert!(matches!(
+            error.error_type,
+            InterpreterError::CallDepthExceeded(20)
+        ));
+        assert_eq!(interpreter.environment.call_depth().depth(), 0);
+    }
+
+    #[test]
+    fn releases_environments_when_dropped() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            import \"counter.fun\" as counter;
+            fn make_counter() {
+                let count = 0;
+                fn increment() {
+                    count = count + 1;
+                    return count;
+                }
+                return increment;
+            }
+            let next = make_counter();
+            next();
+            counter.next();
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let resolver = MemoryResolver::new().set_module(
+            "counter.fun",
+            "let count = 0; export fn next() { count = count + 1; return count; }",
+        );
+        let mut interpreter = Interpreter::new().set_resolver(resolver);
+        assert!(interpreter.interpret(parser_result.unwrap()).is_ok());
+
+        let globals = Rc::downgrade(&interpreter.globals.0);
+        let environment = Rc::downgrade(&interpreter.environment.0);
+        drop(interpreter);
+
+        assert!(globals.upgrade().is_none());
+        assert!(environment.upgrade().is_none());
+    }
+
+    #[test]
+    fn clears_captured_variables_when_dropped() {
+        let tokens = Lexer::new()
+            .tokenize("let base = 10; fn add(value) = value + base;")
+            .unwrap();
+        let statements = Parser::new().parse(tokens).unwrap();
+
+        let mut interpreter = Interpreter::new().set_output(Output::sink());
+        assert!(interpreter.interpret(statements).is_ok());
+        let Some(Expr::Literal(add)) = interpreter.environment().variable("add") else {
+            panic!("expected `add` to be defined");
+        };
+        let mut environment = Environment::new();
+        let result = call_value(
+            &add.literal,
+            &mut environment,
+            vec![LiteralData::Integer(1)],
+            vec![],
+        );
+        assert_eq!(result.unwrap(), LiteralData::Integer(11));
+
+        drop(interpreter);
+        let error = call_value(
+            &add.literal,
+            &mut environment,
+            vec![LiteralData::Integer(1)],
+            vec![],
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "[InvalidIdentifier] variable with identifier `base` does not exist"
+        );
+    }
+
+    #[test]
+    fn resets_strict_pragma_between_runs() {
+        let parse = |source: &str| {
+            let tokens = Lexer::new().tokenize(source).unwrap();
+            Parser::new().parse(tokens).unwrap()
+        };
+
+        let mut interpreter = Interpreter::new().set_output(Output::sink());
+        assert!(interpreter
+            .interpret(parse("\"use strict\"; print 1;"))
+            .is_ok());
+        assert!(interpreter.is_strict());
+        assert!(interpreter.interpret(parse("print \"3\" - true;")).is_ok());
+        assert!(!interpreter.is_strict());
+
+        let mut interpreter = Interpreter::new()
+            .set_output(Output::sink())
+            .set_strict(true);
+        assert!(interpreter.interpret(parse("print 1;")).is_ok());
+        assert!(interpreter.is_strict());
+    }
 }
@@ -0,0 +1,137 @@
+use std::rc::Rc;
+
+use funlang_error::ErrorCascade;
+
+use crate::{
+    environment::Environment,
+    error,
+    errors::InterpreterError,
+    functions::{Callable, Function, NativeFunction},
+    literal::LiteralData,
+    range::Range,
+};
+
+pub enum ValueIterator {
+    Characters(Vec<char>, usize),
+    Function(Function),
+    NativeFunction(NativeFunction),
+    Range(Range, usize),
+    List(Rc<Vec<LiteralData>>, usize),
+}
+
+impl ValueIterator {
+    pub fn new(iterable: LiteralData) -> Result<Self, ErrorCascade<InterpreterError>> {
+        match iterable {
+            LiteralData::String(string_value) => {
+                Ok(Self::Characters(string_value.chars().collect(), 0))
+            }
+            LiteralData::Function(function_value) => Ok(Self::Function(function_value)),
+            LiteralData::NativeFunction(function_value) => Ok(Self::NativeFunction(function_value)),
+            LiteralData::Range(range_value) => Ok(Self::Range(range_value, 0)),
+            LiteralData::List(list_value) => Ok(Self::List(list_value, 0)),
+            iterable => Err(error!(InterpreterError::NotIterable(
+                iterable.type_name().to_string()
+            ))),
+        }
+    }
+
+    pub fn next(
+        &mut self,
+        environment: &mut Environment,
+    ) -> Result<Option<LiteralData>, ErrorCascade<InterpreterError>> {
+        let item = match self {
+            Self::Characters(characters, index) => {
+                let character = characters
+                    .get(*index)
+                    .map(|character| character.to_string());
+                *index += 1;
+                return Ok(character.map(LiteralData::String));
+            }
+            Self::Range(range_value, index) => {
+                let value = range_value.get(*index);
+                *index += 1;
+                return Ok(value.map(LiteralData::Integer));
+            }
+            Self::List(list_value, index) => {
+                let value = list_value.get(*index).cloned();
+                *index += 1;
+                return Ok(value);
+            }
+            Self::Function(function_value) => function_value.call(environment, vec![])?,
+            Self::NativeFunction(function_value) => function_value.call(environment, vec![])?,
+        };
+
+        match item {
+            LiteralData::None => Ok(None),
+            item => Ok(Some(item)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod iterator_tests {
+    use super::*;
+
+    #[test]
+    fn iterates_characters() {
+        let mut environment = Environment::new();
+        let mut iterator = ValueIterator::new(LiteralData::String("añb".to_string())).unwrap();
+
+        let mut items: Vec<LiteralData> = vec![];
+        while let Some(item) = iterator.next(&mut environment).unwrap() {
+            items.push(item);
+        }
+        assert_eq!(
+            items,
+            vec![
+                LiteralData::String("a".to_string()),
+                LiteralData::String("ñ".to_string()),
+                LiteralData::String("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn iterates_native_functions_until_null() {
+        let mut environment = Environment::new();
+        let countdown = std::rc::Rc::new(std::cell::Cell::new(3));
+        let remaining = countdown.clone();
+        let mut iterator = ValueIterator::new(LiteralData::NativeFunction(NativeFunction::new(
+            "countdown",
+            0,
+            move |_environment, _arguments| {
+                let value = remaining.get();
+                remaining.set(value - 1);
+                Ok(if value > 0 {
+                    LiteralData::Integer(value)
+                } else {
+                    LiteralData::None
+                })
+            },
+        )))
+        .unwrap();
+
+        let mut items: Vec<LiteralData> = vec![];
+        while let Some(item) = iterator.next(&mut environment).unwrap() {
+            items.push(item);
+        }
+        assert_eq!(
+            items,
+            vec![
+                LiteralData::Integer(3),
+                LiteralData::Integer(2),
+                LiteralData::Integer(1),
+            ]
+        );
+        assert_eq!(countdown.get(), -1);
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_iterable() {
+        let error = ValueIterator::new(LiteralData::Integer(1)).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "[NotIterable] values of type `integer` can not be iterated"
+        );
+    }
+}
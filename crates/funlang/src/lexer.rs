@@ -1,36 +1,59 @@
 use funlang_error::{ErrorCascade, ErrorSpan};
+use unicode_ident::{is_xid_continue, is_xid_start};
 
 use crate::{
     error,
     errors::LexerError,
+    literal::LiteralData,
     token::{Span, Token, TokenType},
-    token_lit_number, token_lit_string,
+    token_lit_float, token_lit_integer, token_lit_string,
 };
 
 pub struct Lexer<'a> {
     source: Option<&'a str>,
+    characters: Vec<char>,
+    byte_offsets: Vec<usize>,
     current_index: usize,
     lookahead_index: usize,
     line_number: usize,
     col_number: usize,
+    interpolations: Vec<(usize, ErrorSpan)>,
+    pending_doc: Option<String>,
+    previous_token_type: Option<TokenType>,
+}
+
+impl<'a> Default for Lexer<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Lexer<'a> {
     pub fn new() -> Self {
         Self {
             source: None,
+            characters: vec![],
+            byte_offsets: vec![],
             current_index: 0,
             lookahead_index: 0,
             line_number: 1,
             col_number: 1,
+            interpolations: vec![],
+            pending_doc: None,
+            previous_token_type: None,
         }
     }
 
     fn reset_state(&mut self) {
+        self.characters.clear();
+        self.byte_offsets.clear();
         self.current_index = 0;
         self.lookahead_index = 0;
         self.line_number = 1;
         self.col_number = 1;
+        self.interpolations.clear();
+        self.pending_doc = None;
+        self.previous_token_type = None;
     }
 
     fn unwrap_source(&self) -> Result<&'a str, ErrorCascade<LexerError>> {
@@ -38,92 +61,294 @@ impl<'a> Lexer<'a> {
     }
 
     fn is_at_end(&self) -> Result<bool, ErrorCascade<LexerError>> {
-        Ok(self.current_index >= self.unwrap_source()?.len())
+        Ok(self.current_index >= self.characters.len())
     }
 
     fn peek(&self, lookahead_offset: usize) -> Result<char, ErrorCascade<LexerError>> {
         match self
-            .unwrap_source()?
-            .chars()
-            .nth(self.current_index + self.lookahead_index + lookahead_offset)
+            .characters
+            .get(self.current_index + self.lookahead_index + lookahead_offset)
         {
-            Some(c) => Ok(c),
+            Some(c) => Ok(*c),
             None => Ok('\0'),
         }
     }
 
     fn match_next(&mut self, expected: char) -> Result<bool, ErrorCascade<LexerError>> {
-        let is_match = match self
-            .unwrap_source()?
-            .chars()
-            .nth(self.current_index + self.lookahead_index + 1)
-        {
-            Some(next_char) => next_char == expected,
-            None => false,
-        };
+        let is_match = self.peek(1)? == expected;
         if is_match {
             self.lookahead_index += 1;
         }
         Ok(is_match)
     }
 
-    fn string(&mut self) -> Result<Token, ErrorCascade<LexerError>> {
+    fn byte_offset(&self, index: usize) -> Result<usize, ErrorCascade<LexerError>> {
+        match self.byte_offsets.get(index) {
+            Some(byte_offset) => Ok(*byte_offset),
+            None => Ok(self.unwrap_source()?.len()),
+        }
+    }
+
+    fn lexeme(&self) -> Result<&'a str, ErrorCascade<LexerError>> {
+        let start = self.byte_offset(self.current_index)?;
+        let end = self.byte_offset(self.current_index + self.lookahead_index + 1)?;
+        self.unwrap_source()?
+            .get(start..end)
+            .ok_or(error!(LexerError::InvalidCharacterIndex))
+    }
+
+    fn span_at(&self, lookahead_offset: usize, len: usize) -> ErrorSpan {
+        let mut line_number = self.line_number;
+        let mut col_number = self.col_number;
+        for c in self
+            .characters
+            .iter()
+            .skip(self.current_index)
+            .take(lookahead_offset)
+        {
+            if *c == '\n' {
+                line_number += 1;
+                col_number = 1;
+            } else {
+                col_number += 1;
+            }
+        }
+        ErrorSpan::new(line_number, col_number, len)
+    }
+
+    fn token_span(&self) -> Result<Span, ErrorCascade<LexerError>> {
+        Ok(
+            Span::new(self.line_number, self.col_number, self.lookahead_index).set_range(
+                self.byte_offset(self.current_index)?,
+                self.byte_offset(self.current_index + self.lookahead_index)?,
+            ),
+        )
+    }
+
+    fn advance_position(&mut self) {
+        let consumed = self.current_index..self.current_index + self.lookahead_index;
+        for c in &self.characters[consumed] {
+            if *c == '\n' {
+                self.line_number += 1;
+                self.col_number = 1;
+            } else {
+                self.col_number += 1;
+            }
+        }
+        self.current_index += self.lookahead_index;
+        self.lookahead_index = 0;
+    }
+
+    fn unicode_escape(
+        &mut self,
+        lookahead_offset: usize,
+    ) -> Result<char, ErrorCascade<LexerError>> {
+        let mut sequence = String::from("\\u");
+        let mut digits = String::new();
         let mut is_closed = false;
 
-        'crawler: while !self.is_at_end()? {
+        if self.peek(1)? == '{' {
             self.lookahead_index += 1;
-            if self.peek(0)? == '\n' {
-                self.line_number += 1;
+            sequence.push('{');
+            while self.peek(1)?.is_ascii_hexdigit() && digits.len() < 6 {
+                self.lookahead_index += 1;
+                digits.push(self.peek(0)?);
             }
-            if self.peek(0)? == '"' {
+            sequence.push_str(&digits);
+            if self.peek(1)? == '}' {
+                self.lookahead_index += 1;
+                sequence.push('}');
                 is_closed = true;
-                break 'crawler;
             }
         }
 
-        if !is_closed {
-            Err(
-                error!(LexerError::UnterminatedString).set_span(ErrorSpan::new(
-                    self.line_number,
-                    self.col_number,
-                    self.lookahead_index,
-                )),
-            )
-        } else {
-            let literal_value = &self.unwrap_source()?
-                [(self.current_index + 1)..(self.current_index + self.lookahead_index)];
-            Ok(token_lit_string!(literal_value.to_string()))
+        let span = self.span_at(lookahead_offset, sequence.chars().count());
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .filter(|_| is_closed)
+            .and_then(char::from_u32)
+            .ok_or(error!(LexerError::InvalidUnicodeEscape(sequence)).set_span(span))
+    }
+
+    fn escape(&mut self) -> Result<char, ErrorCascade<LexerError>> {
+        let lookahead_offset = self.lookahead_index;
+        self.lookahead_index += 1;
+
+        match self.peek(0)? {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            '0' => Ok('\0'),
+            '"' => Ok('"'),
+            '\\' => Ok('\\'),
+            '$' => Ok('$'),
+            'u' => self.unicode_escape(lookahead_offset),
+            c => Err(error!(LexerError::InvalidEscape(format!("\\{}", c)))
+                .set_span(self.span_at(lookahead_offset, 2))),
+        }
+    }
+
+    fn string(&mut self) -> Result<Token, ErrorCascade<LexerError>> {
+        let mut literal_value = String::new();
+
+        while self.peek(1)? != '\0' {
+            self.lookahead_index += 1;
+            match self.peek(0)? {
+                '"' => return Ok(token_lit_string!(literal_value)),
+                '$' if self.peek(1)? == '{' => {
+                    let span = self.span_at(self.lookahead_index, 2);
+                    self.lookahead_index += 1;
+                    self.interpolations.push((0, span));
+                    return Ok(Token::new(TokenType::Interpolation)
+                        .set_literal_data(LiteralData::String(literal_value)));
+                }
+                '\\' if self.peek(1)? != '\0' => literal_value.push(self.escape()?),
+                '\\' => break,
+                c => literal_value.push(c),
+            }
+        }
+
+        Err(
+            error!(LexerError::UnterminatedString).set_span(ErrorSpan::new(
+                self.line_number,
+                self.col_number,
+                self.lookahead_index,
+            )),
+        )
+    }
+
+    fn is_raw_string_start(&self) -> Result<bool, ErrorCascade<LexerError>> {
+        let mut offset = 1;
+        while self.peek(offset)? == '#' {
+            offset += 1;
+        }
+        Ok(self.peek(offset)? == '"')
+    }
+
+    fn is_raw_string_end(&self, hashes: usize) -> Result<bool, ErrorCascade<LexerError>> {
+        for offset in 1..=hashes {
+            if self.peek(offset)? != '#' {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn raw_string(&mut self) -> Result<Token, ErrorCascade<LexerError>> {
+        let mut literal_value = String::new();
+
+        let mut hashes = 0;
+        while self.peek(1)? == '#' {
+            self.lookahead_index += 1;
+            hashes += 1;
+        }
+        self.lookahead_index += 1;
+
+        while self.peek(1)? != '\0' {
+            self.lookahead_index += 1;
+            let c = self.peek(0)?;
+            if c == '"' && self.is_raw_string_end(hashes)? {
+                self.lookahead_index += hashes;
+                return Ok(token_lit_string!(literal_value));
+            }
+            literal_value.push(c);
+        }
+
+        Err(
+            error!(LexerError::UnterminatedString).set_span(ErrorSpan::new(
+                self.line_number,
+                self.col_number,
+                self.lookahead_index,
+            )),
+        )
+    }
+
+    fn line_comment(&mut self) -> Result<(), ErrorCascade<LexerError>> {
+        let is_doc_comment = self.peek(1)? == '/' && self.peek(2)? != '/';
+
+        while self.peek(1)? != '\n' && self.peek(1)? != '\0' {
+            self.lookahead_index += 1;
         }
+
+        if is_doc_comment {
+            let text = self.lexeme()?.trim_start_matches('/');
+            let text = text.strip_prefix(' ').unwrap_or(text).trim_end();
+            self.pending_doc = Some(match self.pending_doc.take() {
+                Some(doc) => format!("{}\n{}", doc, text),
+                None => text.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn block_comment(&mut self) -> Result<(), ErrorCascade<LexerError>> {
+        let mut depth = 1;
+
+        while depth > 0 {
+            if self.peek(1)? == '\0' {
+                Err(
+                    error!(LexerError::UnterminatedComment).set_span(ErrorSpan::new(
+                        self.line_number,
+                        self.col_number,
+                        2,
+                    )),
+                )?;
+            }
+            self.lookahead_index += 1;
+            if self.peek(0)? == '/' && self.peek(1)? == '*' {
+                self.lookahead_index += 1;
+                depth += 1;
+            } else if self.peek(0)? == '*' && self.peek(1)? == '/' {
+                self.lookahead_index += 1;
+                depth -= 1;
+            }
+        }
+
+        Ok(())
     }
 
     fn number(&mut self) -> Result<Token, ErrorCascade<LexerError>> {
-        while self.peek(1)?.is_digit(10) {
+        let mut is_float = false;
+        while self.peek(1)?.is_ascii_digit() {
             self.lookahead_index += 1;
         }
-        if self.peek(1)? == '.' && self.peek(2)?.is_digit(10) {
+        if self.peek(1)? == '.' && self.peek(2)?.is_ascii_digit() {
+            is_float = true;
             self.lookahead_index += 2;
-            while self.peek(1)?.is_digit(10) {
+            while self.peek(1)?.is_ascii_digit() {
                 self.lookahead_index += 1;
             }
         }
-        let literal_value = &self.unwrap_source()?
-            [self.current_index..self.current_index + self.lookahead_index + 1];
-        let parsed_literal_value =
-            literal_value
-                .parse::<f32>()
-                .or(Err(error!(LexerError::InvalidCharacterIndex).set_span(
-                    ErrorSpan::new(self.line_number, self.col_number, self.lookahead_index),
-                )))?;
-        Ok(token_lit_number!(parsed_literal_value))
+        let literal_value = self.lexeme()?;
+        let span = ErrorSpan::new(self.line_number, self.col_number, self.lookahead_index + 1);
+        if is_float {
+            let parsed_literal_value = literal_value
+                .parse::<f64>()
+                .or(Err(error!(LexerError::InvalidCharacterIndex).set_span(span)))?;
+            Ok(token_lit_float!(parsed_literal_value))
+        } else if self.previous_token_type == Some(TokenType::Minus)
+            && literal_value.parse::<u64>() == Ok(i64::MIN.unsigned_abs())
+        {
+            Ok(token_lit_integer!(i64::MIN))
+        } else {
+            let parsed_literal_value =
+                literal_value
+                    .parse::<i64>()
+                    .or(Err(error!(LexerError::IntegerOutOfRange(
+                        literal_value.to_string()
+                    ))
+                    .set_span(span)))?;
+            Ok(token_lit_integer!(parsed_literal_value))
+        }
     }
 
     fn identifier(&mut self) -> Result<Token, ErrorCascade<LexerError>> {
-        while self.peek(1)?.is_alphanumeric() {
+        while is_xid_continue(self.peek(1)?) {
             self.lookahead_index += 1;
         }
 
-        let literal_value = &self.unwrap_source()?
-            [self.current_index..self.current_index + self.lookahead_index + 1];
+        let literal_value = self.lexeme()?;
         let parsed_keyword = TokenType::get_keyword(literal_value);
 
         let token = match parsed_keyword {
@@ -133,7 +358,6 @@ impl<'a> Lexer<'a> {
 
         Ok(token)
     }
-
     fn identify_token(&mut self) -> Result<Option<Token>, ErrorCascade<LexerError>> {
         let c = self.peek(0)?;
 
@@ -143,14 +367,90 @@ impl<'a> Lexer<'a> {
                 ')' => Ok(Some(Token::new(TokenType::RightParen))),
                 '[' => Ok(Some(Token::new(TokenType::LeftBracket))),
                 ']' => Ok(Some(Token::new(TokenType::RightBracket))),
-                '{' => Ok(Some(Token::new(TokenType::LeftBrace))),
-                '}' => Ok(Some(Token::new(TokenType::RightBrace))),
-                '.' => Ok(Some(Token::new(TokenType::Dot))),
+                '{' => {
+                    if let Some((depth, _)) = self.interpolations.last_mut() {
+                        *depth += 1;
+                    }
+                    Ok(Some(Token::new(TokenType::LeftBrace)))
+                }
+                '}' => match self.interpolations.last_mut() {
+                    Some((0, _)) => {
+                        self.interpolations.pop();
+                        Ok(Some(self.string()?))
+                    }
+                    Some((depth, _)) => {
+                        *depth -= 1;
+                        Ok(Some(Token::new(TokenType::RightBrace)))
+                    }
+                    None => Ok(Some(Token::new(TokenType::RightBrace))),
+                },
+                '.' => {
+                    if self.match_next('.')? {
+                        if self.match_next('=')? {
+                            Ok(Some(Token::new(TokenType::DotDotEqual)))
+                        } else if self.match_next('.')? {
+                            Ok(Some(Token::new(TokenType::DotDotDot)))
+                        } else {
+                            Ok(Some(Token::new(TokenType::DotDot)))
+                        }
+                    } else {
+                        Ok(Some(Token::new(TokenType::Dot)))
+                    }
+                }
                 ',' => Ok(Some(Token::new(TokenType::Comma))),
-                '-' => Ok(Some(Token::new(TokenType::Minus))),
-                '+' => Ok(Some(Token::new(TokenType::Plus))),
+                ':' => Ok(Some(Token::new(TokenType::Colon))),
+                '-' => {
+                    if self.match_next('>')? {
+                        Ok(Some(Token::new(TokenType::Arrow)))
+                    } else if self.match_next('=')? {
+                        Ok(Some(Token::new(TokenType::MinusEqual)))
+                    } else {
+                        Ok(Some(Token::new(TokenType::Minus)))
+                    }
+                }
+                '+' => {
+                    if self.match_next('=')? {
+                        Ok(Some(Token::new(TokenType::PlusEqual)))
+                    } else {
+                        Ok(Some(Token::new(TokenType::Plus)))
+                    }
+                }
                 ';' => Ok(Some(Token::new(TokenType::Semicolon))),
-                '*' => Ok(Some(Token::new(TokenType::Star))),
+                '*' => {
+                    if self.match_next('*')? {
+                        Ok(Some(Token::new(TokenType::StarStar)))
+                    } else if self.match_next('=')? {
+                        Ok(Some(Token::new(TokenType::StarEqual)))
+                    } else {
+                        Ok(Some(Token::new(TokenType::Star)))
+                    }
+                }
+                '%' => {
+                    if self.match_next('=')? {
+                        Ok(Some(Token::new(TokenType::PercentEqual)))
+                    } else {
+                        Ok(Some(Token::new(TokenType::Percent)))
+                    }
+                }
+                '&' => Ok(Some(Token::new(TokenType::Ampersand))),
+                '|' => {
+                    if self.match_next('>')? {
+                        Ok(Some(Token::new(TokenType::PipeGreater)))
+                    } else {
+                        Ok(Some(Token::new(TokenType::Pipe)))
+                    }
+                }
+                '^' => Ok(Some(Token::new(TokenType::Caret))),
+                '~' => Ok(Some(Token::new(TokenType::Tilde))),
+                '?' => {
+                    if self.match_next('?')? {
+                        Ok(Some(Token::new(TokenType::QuestionQuestion)))
+                    } else if self.match_next('.')? {
+                        Ok(Some(Token::new(TokenType::QuestionDot)))
+                    } else {
+                        Ok(Some(Token::new(TokenType::Question)))
+                    }
+                }
                 '!' => {
                     if self.match_next('=')? {
                         Ok(Some(Token::new(TokenType::BangEqual)))
@@ -161,6 +461,8 @@ impl<'a> Lexer<'a> {
                 '=' => {
                     if self.match_next('=')? {
                         Ok(Some(Token::new(TokenType::EqualEqual)))
+                    } else if self.match_next('>')? {
+                        Ok(Some(Token::new(TokenType::FatArrow)))
                     } else {
                         Ok(Some(Token::new(TokenType::Equal)))
                     }
@@ -168,6 +470,8 @@ impl<'a> Lexer<'a> {
                 '<' => {
                     if self.match_next('=')? {
                         Ok(Some(Token::new(TokenType::LessEqual)))
+                    } else if self.match_next('<')? {
+                        Ok(Some(Token::new(TokenType::LessLess)))
                     } else {
                         Ok(Some(Token::new(TokenType::Less)))
                     }
@@ -175,16 +479,21 @@ impl<'a> Lexer<'a> {
                 '>' => {
                     if self.match_next('=')? {
                         Ok(Some(Token::new(TokenType::GreaterEqual)))
+                    } else if self.match_next('>')? {
+                        Ok(Some(Token::new(TokenType::GreaterGreater)))
                     } else {
                         Ok(Some(Token::new(TokenType::Greater)))
                     }
                 }
                 '/' => {
                     if self.match_next('/')? {
-                        while self.peek(1)? != '\n' && !self.is_at_end()? {
-                            self.lookahead_index += 1;
-                        }
+                        self.line_comment()?;
+                        Ok(None)
+                    } else if self.match_next('*')? {
+                        self.block_comment()?;
                         Ok(None)
+                    } else if self.match_next('=')? {
+                        Ok(Some(Token::new(TokenType::SlashEqual)))
                     } else {
                         Ok(Some(Token::new(TokenType::Slash)))
                     }
@@ -192,19 +501,17 @@ impl<'a> Lexer<'a> {
                 ' ' => Ok(None),
                 '\r' => Ok(None),
                 '\t' => Ok(None),
-                '\n' => {
-                    self.line_number += 1;
-                    self.col_number = 0;
-                    Ok(None)
-                }
+                '\n' => Ok(None),
                 '"' => Ok(Some(self.string()?)),
+                'r' if self.is_raw_string_start()? => Ok(Some(self.raw_string()?)),
                 c => {
-                    if c.is_digit(10) {
+                    if c.is_ascii_digit() {
                         Ok(Some(self.number()?))
-                    } else if c.is_alphabetic() {
+                    } else if c == '_' || is_xid_start(c) {
                         Ok(Some(self.identifier()?))
                     } else {
-                        Err(error!(LexerError::UnexpectedCharacter(c.to_string())))
+                        Err(error!(LexerError::UnexpectedCharacter(c.to_string()))
+                            .set_span(ErrorSpan::new(self.line_number, self.col_number, 1)))
                     }
                 }
             };
@@ -215,20 +522,18 @@ impl<'a> Lexer<'a> {
         }?;
 
         let token = match token {
-            Some(token) => Some(token.set_span(Span::new(
-                self.line_number,
-                self.col_number,
-                self.lookahead_index,
-            ))),
+            Some(token) => {
+                self.previous_token_type = Some(token.token_type.clone());
+                let token = token.set_span(self.token_span()?);
+                match self.pending_doc.take() {
+                    Some(doc) => Some(token.set_doc(doc)),
+                    None => Some(token),
+                }
+            }
             None => None,
         };
 
-        if self.peek(0)? != '\n' {
-            self.col_number += self.lookahead_index;
-        }
-
-        self.current_index += self.lookahead_index;
-        self.lookahead_index = 0;
+        self.advance_position();
 
         Ok(token)
     }
@@ -236,6 +541,10 @@ impl<'a> Lexer<'a> {
     pub fn tokenize(&mut self, source: &'a str) -> Result<Vec<Token>, ErrorCascade<LexerError>> {
         self.reset_state();
         self.source = Some(source);
+        for (byte_offset, c) in source.char_indices() {
+            self.byte_offsets.push(byte_offset);
+            self.characters.push(c);
+        }
 
         let mut tokens: Vec<Token> = vec![];
 
@@ -245,11 +554,11 @@ impl<'a> Lexer<'a> {
             }
         }
 
-        tokens.push(Token::new(TokenType::EOF).set_span(Span::new(
-            self.line_number,
-            self.col_number,
-            0,
-        )));
+        if let Some((_, span)) = self.interpolations.last() {
+            Err(error!(LexerError::UnterminatedInterpolation).set_span(span.clone()))?;
+        }
+
+        tokens.push(Token::new(TokenType::EOF).set_span(self.token_span()?));
 
         Ok(tokens)
     }
@@ -262,7 +571,7 @@ mod lexer_tests {
     #[test]
     fn parses_single_character_lexemes() {
         let mut lexer = Lexer::new();
-        let result = lexer.tokenize("[](){},.-+;*/");
+        let result = lexer.tokenize("[](){},.-+;*/:");
 
         assert!(result.is_ok());
         assert_eq!(
@@ -270,20 +579,23 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::LeftBracket).set_span(Span::new(1, 1, 1)),
-                    Token::new(TokenType::RightBracket).set_span(Span::new(1, 2, 1)),
-                    Token::new(TokenType::LeftParen).set_span(Span::new(1, 3, 1)),
-                    Token::new(TokenType::RightParen).set_span(Span::new(1, 4, 1)),
-                    Token::new(TokenType::LeftBrace).set_span(Span::new(1, 5, 1)),
-                    Token::new(TokenType::RightBrace).set_span(Span::new(1, 6, 1)),
-                    Token::new(TokenType::Comma).set_span(Span::new(1, 7, 1)),
-                    Token::new(TokenType::Dot).set_span(Span::new(1, 8, 1)),
-                    Token::new(TokenType::Minus).set_span(Span::new(1, 9, 1)),
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 10, 1)),
-                    Token::new(TokenType::Semicolon).set_span(Span::new(1, 11, 1)),
-                    Token::new(TokenType::Star).set_span(Span::new(1, 12, 1)),
-                    Token::new(TokenType::Slash).set_span(Span::new(1, 13, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(1, 14, 0))
+                    Token::new(TokenType::LeftBracket).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::RightBracket)
+                        .set_span(Span::new(1, 2, 1).set_range(1, 2)),
+                    Token::new(TokenType::LeftParen).set_span(Span::new(1, 3, 1).set_range(2, 3)),
+                    Token::new(TokenType::RightParen).set_span(Span::new(1, 4, 1).set_range(3, 4)),
+                    Token::new(TokenType::LeftBrace).set_span(Span::new(1, 5, 1).set_range(4, 5)),
+                    Token::new(TokenType::RightBrace).set_span(Span::new(1, 6, 1).set_range(5, 6)),
+                    Token::new(TokenType::Comma).set_span(Span::new(1, 7, 1).set_range(6, 7)),
+                    Token::new(TokenType::Dot).set_span(Span::new(1, 8, 1).set_range(7, 8)),
+                    Token::new(TokenType::Minus).set_span(Span::new(1, 9, 1).set_range(8, 9)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 10, 1).set_range(9, 10)),
+                    Token::new(TokenType::Semicolon)
+                        .set_span(Span::new(1, 11, 1).set_range(10, 11)),
+                    Token::new(TokenType::Star).set_span(Span::new(1, 12, 1).set_range(11, 12)),
+                    Token::new(TokenType::Slash).set_span(Span::new(1, 13, 1).set_range(12, 13)),
+                    Token::new(TokenType::Colon).set_span(Span::new(1, 14, 1).set_range(13, 14)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 15, 0).set_range(14, 14))
                 ]
             )
         )
@@ -292,7 +604,7 @@ mod lexer_tests {
     #[test]
     fn parses_one_or_two_character_lexemes() {
         let mut lexer = Lexer::new();
-        let result = lexer.tokenize("!!====<<=>>=");
+        let result = lexer.tokenize("!!====<=<>=>->");
 
         assert!(result.is_ok());
         assert_eq!(
@@ -300,15 +612,44 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::Bang).set_span(Span::new(1, 1, 1)),
-                    Token::new(TokenType::BangEqual).set_span(Span::new(1, 2, 2)),
-                    Token::new(TokenType::EqualEqual).set_span(Span::new(1, 4, 2)),
-                    Token::new(TokenType::Equal).set_span(Span::new(1, 6, 1)),
-                    Token::new(TokenType::Less).set_span(Span::new(1, 7, 1)),
-                    Token::new(TokenType::LessEqual).set_span(Span::new(1, 8, 2)),
-                    Token::new(TokenType::Greater).set_span(Span::new(1, 10, 1)),
-                    Token::new(TokenType::GreaterEqual).set_span(Span::new(1, 11, 2)),
-                    Token::new(TokenType::EOF).set_span(Span::new(1, 13, 0)),
+                    Token::new(TokenType::Bang).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::BangEqual).set_span(Span::new(1, 2, 2).set_range(1, 3)),
+                    Token::new(TokenType::EqualEqual).set_span(Span::new(1, 4, 2).set_range(3, 5)),
+                    Token::new(TokenType::Equal).set_span(Span::new(1, 6, 1).set_range(5, 6)),
+                    Token::new(TokenType::LessEqual).set_span(Span::new(1, 7, 2).set_range(6, 8)),
+                    Token::new(TokenType::Less).set_span(Span::new(1, 9, 1).set_range(8, 9)),
+                    Token::new(TokenType::GreaterEqual)
+                        .set_span(Span::new(1, 10, 2).set_range(9, 11)),
+                    Token::new(TokenType::Greater).set_span(Span::new(1, 12, 1).set_range(11, 12)),
+                    Token::new(TokenType::Arrow).set_span(Span::new(1, 13, 2).set_range(12, 14)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 15, 0).set_range(14, 14)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn parses_arithmetic_and_bitwise_operators() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize("%***&|^~<<>>");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    Token::new(TokenType::Percent).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::StarStar).set_span(Span::new(1, 2, 2).set_range(1, 3)),
+                    Token::new(TokenType::Star).set_span(Span::new(1, 4, 1).set_range(3, 4)),
+                    Token::new(TokenType::Ampersand).set_span(Span::new(1, 5, 1).set_range(4, 5)),
+                    Token::new(TokenType::Pipe).set_span(Span::new(1, 6, 1).set_range(5, 6)),
+                    Token::new(TokenType::Caret).set_span(Span::new(1, 7, 1).set_range(6, 7)),
+                    Token::new(TokenType::Tilde).set_span(Span::new(1, 8, 1).set_range(7, 8)),
+                    Token::new(TokenType::LessLess).set_span(Span::new(1, 9, 2).set_range(8, 10)),
+                    Token::new(TokenType::GreaterGreater)
+                        .set_span(Span::new(1, 11, 2).set_range(10, 12)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 13, 0).set_range(12, 12)),
                 ]
             )
         )
@@ -325,9 +666,9 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1)),
-                    Token::new(TokenType::Plus).set_span(Span::new(2, 1, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(2, 2, 0)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::Plus).set_span(Span::new(2, 1, 1).set_range(10, 11)),
+                    Token::new(TokenType::EOF).set_span(Span::new(2, 2, 0).set_range(11, 11)),
                 ]
             )
         )
@@ -344,9 +685,9 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1)),
-                    Token::new(TokenType::Plus).set_span(Span::new(2, 1, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(2, 2, 0)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::Plus).set_span(Span::new(2, 1, 1).set_range(5, 6)),
+                    Token::new(TokenType::EOF).set_span(Span::new(2, 2, 0).set_range(6, 6)),
                 ]
             )
         )
@@ -363,10 +704,11 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1)),
-                    token_lit_string!("Example string".to_string()).set_span(Span::new(1, 2, 16)),
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 18, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(1, 19, 0)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    token_lit_string!("Example string".to_string())
+                        .set_span(Span::new(1, 2, 16).set_range(1, 17)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 18, 1).set_range(17, 18)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 19, 0).set_range(18, 18)),
                 ]
             )
         )
@@ -383,15 +725,109 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1)),
-                    token_lit_number!(1232.23_f32).set_span(Span::new(1, 2, 7)),
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 9, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(1, 10, 0)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    token_lit_float!(1232.23).set_span(Span::new(1, 2, 7).set_range(1, 8)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 9, 1).set_range(8, 9)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 10, 0).set_range(9, 9)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn distinguishes_integer_and_float_literals() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize("16777217 1.0");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    token_lit_integer!(16777217).set_span(Span::new(1, 1, 8).set_range(0, 8)),
+                    token_lit_float!(1.0).set_span(Span::new(1, 10, 3).set_range(9, 12)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 13, 0).set_range(12, 12)),
                 ]
             )
         )
     }
 
+    #[test]
+    fn processes_string_escapes() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize(r#""a\n\t\"b\"\\\u{1F600}""#);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    token_lit_string!("a\n\t\"b\"\\\u{1F600}".to_string())
+                        .set_span(Span::new(1, 1, 23).set_range(0, 23)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 24, 0).set_range(23, 23)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn keeps_raw_strings_verbatim() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize(r##"r"\d+\n" r#"say "hi""#"##);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    token_lit_string!("\\d+\\n".to_string())
+                        .set_span(Span::new(1, 1, 8).set_range(0, 8)),
+                    token_lit_string!("say \"hi\"".to_string())
+                        .set_span(Span::new(1, 10, 13).set_range(9, 22)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 23, 0).set_range(22, 22)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn splits_interpolated_strings() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize(r#""a${b}c""#);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    Token::new(TokenType::Interpolation)
+                        .set_literal_data(LiteralData::String("a".to_string()))
+                        .set_span(Span::new(1, 1, 4).set_range(0, 4)),
+                    Token::new(TokenType::Identifier)
+                        .set_lexeme("b".to_string())
+                        .set_span(Span::new(1, 5, 1).set_range(4, 5)),
+                    token_lit_string!("c".to_string()).set_span(Span::new(1, 6, 3).set_range(5, 8)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 9, 0).set_range(8, 8)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn rejects_invalid_escapes() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize(r#""ok \q""#);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "[line 1:5 - InvalidEscape] invalid escape sequence `\\q`"
+        );
+    }
+
     #[test]
     fn parses_identifiers() {
         let mut lexer = Lexer::new();
@@ -403,12 +839,12 @@ mod lexer_tests {
             format!(
                 "{:?}",
                 vec![
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1).set_range(0, 1)),
                     Token::new(TokenType::Identifier)
                         .set_lexeme("abcd1234".to_string())
-                        .set_span(Span::new(1, 2, 8)),
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 10, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(1, 11, 0)),
+                        .set_span(Span::new(1, 2, 8).set_range(1, 9)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 10, 1).set_range(9, 10)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 11, 0).set_range(10, 10)),
                 ]
             )
         )
@@ -427,14 +863,98 @@ mod lexer_tests {
                 vec![
                     Token::new(TokenType::Identifier)
                         .set_lexeme("h".to_string())
-                        .set_span(Span::new(1, 1, 1)),
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 2, 1)),
-                    Token::new(TokenType::And).set_span(Span::new(1, 3, 3)),
-                    Token::new(TokenType::Plus).set_span(Span::new(1, 6, 1)),
+                        .set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 2, 1).set_range(1, 2)),
+                    Token::new(TokenType::And).set_span(Span::new(1, 3, 3).set_range(2, 5)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 6, 1).set_range(5, 6)),
                     Token::new(TokenType::Identifier)
                         .set_lexeme("h".to_string())
-                        .set_span(Span::new(1, 7, 1)),
-                    Token::new(TokenType::EOF).set_span(Span::new(1, 8, 0)),
+                        .set_span(Span::new(1, 7, 1).set_range(6, 7)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 8, 0).set_range(7, 7)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn tracks_byte_ranges_and_columns_separately() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize("\"é\" _max_retries");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    token_lit_string!("é".to_string()).set_span(Span::new(1, 1, 3).set_range(0, 4)),
+                    Token::new(TokenType::Identifier)
+                        .set_lexeme("_max_retries".to_string())
+                        .set_span(Span::new(1, 5, 12).set_range(5, 17)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 17, 0).set_range(17, 17)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn parses_unicode_identifiers() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize("café+名前");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    Token::new(TokenType::Identifier)
+                        .set_lexeme("café".to_string())
+                        .set_span(Span::new(1, 1, 4).set_range(0, 5)),
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 5, 1).set_range(5, 6)),
+                    Token::new(TokenType::Identifier)
+                        .set_lexeme("名前".to_string())
+                        .set_span(Span::new(1, 6, 2).set_range(6, 12)),
+                    Token::new(TokenType::EOF).set_span(Span::new(1, 8, 0).set_range(12, 12)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn skips_nested_block_comments() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize("+/* a /* b */\n c */+");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    Token::new(TokenType::Plus).set_span(Span::new(1, 1, 1).set_range(0, 1)),
+                    Token::new(TokenType::Plus).set_span(Span::new(2, 6, 1).set_range(19, 20)),
+                    Token::new(TokenType::EOF).set_span(Span::new(2, 7, 0).set_range(20, 20)),
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn attaches_doc_comments_to_the_next_token() {
+        let mut lexer = Lexer::new();
+        let result = lexer.tokenize("/// first\n///second\n//// plain\nfn");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            format!("{:?}", result.unwrap()),
+            format!(
+                "{:?}",
+                vec![
+                    Token::new(TokenType::Fn)
+                        .set_span(Span::new(4, 1, 2).set_range(31, 33))
+                        .set_doc("first\nsecond".to_string()),
+                    Token::new(TokenType::EOF).set_span(Span::new(4, 3, 0).set_range(33, 33)),
                 ]
             )
         )
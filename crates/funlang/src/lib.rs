@@ -1,10 +1,19 @@
 pub mod ast;
+pub mod builtins;
+pub mod checker;
+pub mod docs;
 pub mod environment;
 pub mod errors;
+pub mod functions;
 pub mod interpreter;
+pub mod iterator;
 pub mod lexer;
 pub mod literal;
 pub mod macros;
+pub mod modules;
+pub mod output;
 pub mod parser;
+pub mod range;
+pub mod testing;
 pub mod token;
-pub mod functions;
\ No newline at end of file
+pub mod types;
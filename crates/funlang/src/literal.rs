@@ -1,41 +1,180 @@
 use std::{
-    cmp::Ordering,
     fmt::{Debug, Display},
-    ops::{Add, Div, Mul, Sub},
+    ops::{Add, Div, Mul, Rem, Sub},
+    rc::Rc,
 };
 
 use funlang_error::ErrorCascade;
 
-use crate::{error, errors::InterpreterError, functions::Function, parse_string_to_num};
+use crate::{
+    error,
+    errors::InterpreterError,
+    functions::{Function, NativeFunction},
+    modules::Module,
+    parse_string_to_num,
+    range::Range,
+};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub enum LiteralData {
     String(String),
-    Number(f32),
+    Integer(i64),
+    Float(f64),
     Bool(bool),
     Function(Function),
+    NativeFunction(NativeFunction),
+    Module(Rc<Module>),
+    Range(Range),
+    List(Rc<Vec<LiteralData>>),
     None,
 }
 
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Number {
+    fn float(self) -> f64 {
+        match self {
+            Self::Integer(integer_value) => integer_value as f64,
+            Self::Float(float_value) => float_value,
+        }
+    }
+
+    fn is_zero(self) -> bool {
+        match self {
+            Self::Integer(integer_value) => integer_value == 0,
+            Self::Float(float_value) => float_value == 0.0,
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(self_value), Self::Integer(other_value)) => self_value == other_value,
+            _ => self.float() == other.float(),
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Self::Integer(self_value), Self::Integer(other_value)) => {
+                self_value.partial_cmp(other_value)
+            }
+            _ => self.float().partial_cmp(&other.float()),
+        }
+    }
+}
+
 impl LiteralData {
-    fn parse_num(&self) -> Result<f32, ErrorCascade<InterpreterError>> {
+    fn parse_num(&self) -> Result<Number, ErrorCascade<InterpreterError>> {
         match self {
-            Self::Bool(bool_value) => Ok(if bool_value.clone() { 1.0 } else { 0.0 }),
-            Self::Number(number_value) => Ok(number_value.clone()),
+            Self::Bool(bool_value) => Ok(Number::Integer(if *bool_value { 1 } else { 0 })),
+            Self::Integer(integer_value) => Ok(Number::Integer(*integer_value)),
+            Self::Float(float_value) => Ok(Number::Float(*float_value)),
             Self::String(string_value) => {
                 let parsed_string_value = parse_string_to_num!(
                     string_value,
                     error!(InterpreterError::InvalidParsedNumber(string_value.clone()))
                 )?;
-                Ok(parsed_string_value)
+                match parsed_string_value {
+                    Self::Integer(integer_value) => Ok(Number::Integer(integer_value)),
+                    Self::Float(float_value) => Ok(Number::Float(float_value)),
+                    _ => Err(error!(InterpreterError::InvalidParsedNumber(
+                        string_value.clone()
+                    ))),
+                }
+            }
+            Self::None => Ok(Number::Integer(0)),
+            Self::Function(_) => Ok(Number::Integer(1)),
+            Self::NativeFunction(_) => Ok(Number::Integer(1)),
+            Self::Module(_) => Ok(Number::Integer(1)),
+            Self::Range(range_value) => Ok(Number::Integer(!range_value.is_empty() as i64)),
+            Self::List(list_value) => Ok(Number::Integer(!list_value.is_empty() as i64)),
+        }
+    }
+
+    fn arithmetic(
+        &self,
+        rhs: &Self,
+        integer_operation: fn(i64, i64) -> Option<i64>,
+        float_operation: fn(f64, f64) -> f64,
+    ) -> Result<Self, ErrorCascade<InterpreterError>> {
+        match (self.parse_num()?, rhs.parse_num()?) {
+            (Number::Integer(self_value), Number::Integer(other_value)) => {
+                integer_operation(self_value, other_value)
+                    .map(Self::Integer)
+                    .ok_or(error!(InterpreterError::IntegerOverflow))
             }
-            Self::None => Ok(0.0),
-            Self::Function(_) => Ok(1.0),
+            (self_value, other_value) => Ok(Self::Float(float_operation(
+                self_value.float(),
+                other_value.float(),
+            ))),
+        }
+    }
+
+    pub fn floor_div(self, rhs: Self) -> Result<Self, ErrorCascade<InterpreterError>> {
+        match (self.parse_num()?, rhs.parse_num()?) {
+            (Number::Integer(_), Number::Integer(0)) => {
+                Err(error!(InterpreterError::DivisionByZero))
+            }
+            (Number::Integer(dividend), Number::Integer(divisor)) => dividend
+                .checked_div(divisor)
+                .map(|quotient| {
+                    if dividend % divisor != 0 && (dividend < 0) != (divisor < 0) {
+                        quotient - 1
+                    } else {
+                        quotient
+                    }
+                })
+                .map(Self::Integer)
+                .ok_or(error!(InterpreterError::IntegerOverflow)),
+            (dividend, divisor) => Ok(Self::Float((dividend.float() / divisor.float()).floor())),
         }
     }
 
+    pub fn pow(self, rhs: Self) -> Result<Self, ErrorCascade<InterpreterError>> {
+        match (self.parse_num()?, rhs.parse_num()?) {
+            (Number::Integer(base), Number::Integer(exponent)) if exponent >= 0 => {
+                u32::try_from(exponent)
+                    .ok()
+                    .and_then(|exponent| base.checked_pow(exponent))
+                    .map(Self::Integer)
+                    .ok_or(error!(InterpreterError::IntegerOverflow))
+            }
+            (base, exponent) => Ok(Self::Float(base.float().powf(exponent.float()))),
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::Integer(_) => "integer",
+            Self::Float(_) => "float",
+            Self::Bool(_) => "bool",
+            Self::Function(_) | Self::NativeFunction(_) => "function",
+            Self::Module(_) => "module",
+            Self::Range(_) => "range",
+            Self::List(_) => "list",
+            Self::None => "null",
+        }
+    }
+
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Self::Function(_) | Self::NativeFunction(_))
+    }
+
+    pub fn is_number(&self) -> bool {
+        matches!(self, Self::Integer(_) | Self::Float(_))
+    }
+
     pub fn is_truthy(&self) -> Result<bool, ErrorCascade<InterpreterError>> {
-        Ok(self.parse_num()? != 0.0)
+        Ok(!self.parse_num()?.is_zero())
     }
 
     pub fn is_falsy(&self) -> Result<bool, ErrorCascade<InterpreterError>> {
@@ -43,18 +182,41 @@ impl LiteralData {
     }
 }
 
+impl PartialEq for LiteralData {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::String(self_value), Self::String(other_value)) => self_value == other_value,
+            (Self::Bool(self_value), Self::Bool(other_value)) => self_value == other_value,
+            (Self::Function(self_value), Self::Function(other_value)) => self_value == other_value,
+            (Self::NativeFunction(self_value), Self::NativeFunction(other_value)) => {
+                self_value == other_value
+            }
+            (Self::Range(self_value), Self::Range(other_value)) => self_value == other_value,
+            (Self::List(self_value), Self::List(other_value)) => self_value == other_value,
+            (Self::Module(self_value), Self::Module(other_value)) => {
+                Rc::ptr_eq(self_value, other_value)
+            }
+            (Self::None, Self::None) => true,
+            (Self::Integer(_) | Self::Float(_), Self::Integer(_) | Self::Float(_)) => {
+                match (self.parse_num(), other.parse_num()) {
+                    (Ok(self_value), Ok(other_value)) => self_value == other_value,
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
 impl Add for LiteralData {
     type Output = Result<Self, ErrorCascade<InterpreterError>>;
     fn add(self, rhs: Self) -> Self::Output {
         match self {
             Self::String(ref addend1) => match rhs {
-                Self::Function(ref addend2) => Ok(Self::String(format!("{}{}", addend1, addend2))),
-                Self::Bool(ref addend2) => Ok(Self::String(format!("{}{}", addend1, addend2))),
-                Self::String(ref addend2) => Ok(Self::String(format!("{}{}", addend1, addend2))),
-                Self::Number(ref addend2) => Ok(Self::String(format!("{}{}", addend1, addend2))),
                 Self::None => Ok(Self::String(format!("{}null", addend1))),
+                ref addend2 => Ok(Self::String(format!("{}{}", addend1, addend2))),
             },
-            _ => Ok(Self::Number(self.parse_num()? + rhs.parse_num()?)),
+            _ => self.arithmetic(&rhs, i64::checked_add, |a, b| a + b),
         }
     }
 }
@@ -62,75 +224,69 @@ impl Add for LiteralData {
 impl Sub for LiteralData {
     type Output = Result<Self, ErrorCascade<InterpreterError>>;
     fn sub(self, rhs: Self) -> Self::Output {
-        Ok(Self::Number(self.parse_num()? - rhs.parse_num()?))
+        self.arithmetic(&rhs, i64::checked_sub, |a, b| a - b)
     }
 }
 
 impl Mul for LiteralData {
     type Output = Result<Self, ErrorCascade<InterpreterError>>;
     fn mul(self, rhs: Self) -> Self::Output {
-        Ok(Self::Number(self.parse_num()? * rhs.parse_num()?))
+        self.arithmetic(&rhs, i64::checked_mul, |a, b| a * b)
     }
 }
 
 impl Div for LiteralData {
     type Output = Result<Self, ErrorCascade<InterpreterError>>;
     fn div(self, rhs: Self) -> Self::Output {
-        Ok(Self::Number(self.parse_num()? / rhs.parse_num()?))
+        match (self.parse_num()?, rhs.parse_num()?) {
+            (Number::Integer(_), Number::Integer(0)) => {
+                Err(error!(InterpreterError::DivisionByZero))
+            }
+            (dividend, divisor) => Ok(Self::Float(dividend.float() / divisor.float())),
+        }
     }
 }
 
-impl PartialOrd for LiteralData {
-    fn gt(&self, other: &Self) -> bool {
-        match self.parse_num() {
-            Ok(self_value) => match other.parse_num() {
-                Ok(other_value) => self_value > other_value,
-                _ => false,
-            },
-            _ => false,
-        }
-    }
-    fn ge(&self, other: &Self) -> bool {
-        match self.parse_num() {
-            Ok(self_value) => match other.parse_num() {
-                Ok(other_value) => self_value >= other_value,
-                _ => false,
-            },
-            _ => false,
-        }
-    }
-    fn le(&self, other: &Self) -> bool {
-        match self.parse_num() {
-            Ok(self_value) => match other.parse_num() {
-                Ok(other_value) => self_value <= other_value,
-                _ => false,
-            },
-            _ => false,
-        }
-    }
-    fn lt(&self, other: &Self) -> bool {
-        match self.parse_num() {
-            Ok(self_value) => match other.parse_num() {
-                Ok(other_value) => self_value < other_value,
-                _ => false,
-            },
-            _ => false,
+impl Rem for LiteralData {
+    type Output = Result<Self, ErrorCascade<InterpreterError>>;
+    fn rem(self, rhs: Self) -> Self::Output {
+        match (self.parse_num()?, rhs.parse_num()?) {
+            (Number::Integer(_), Number::Integer(0)) => {
+                Err(error!(InterpreterError::DivisionByZero))
+            }
+            (Number::Integer(dividend), Number::Integer(divisor)) => {
+                let remainder = dividend.wrapping_rem(divisor);
+                if remainder != 0 && (remainder < 0) != (divisor < 0) {
+                    Ok(Self::Integer(remainder + divisor))
+                } else {
+                    Ok(Self::Integer(remainder))
+                }
+            }
+            (dividend, divisor) => {
+                let (dividend, divisor) = (dividend.float(), divisor.float());
+                Ok(Self::Float(
+                    dividend - divisor * (dividend / divisor).floor(),
+                ))
+            }
         }
     }
+}
+
+impl PartialOrd for LiteralData {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.parse_num() {
-            Ok(self_value) => match other.parse_num() {
-                Ok(other_value) => {
-                    if self_value == other_value {
-                        Some(Ordering::Equal)
-                    } else if self_value > other_value {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Equal)
-                    }
+        match (self, other) {
+            (Self::String(self_value), Self::String(other_value)) => {
+                self_value.partial_cmp(other_value)
+            }
+            (Self::Bool(self_value), Self::Bool(other_value)) => {
+                self_value.partial_cmp(other_value)
+            }
+            _ if self.is_number() && other.is_number() => {
+                match (self.parse_num(), other.parse_num()) {
+                    (Ok(self_value), Ok(other_value)) => self_value.partial_cmp(&other_value),
+                    _ => None,
                 }
-                _ => None,
-            },
+            }
             _ => None,
         }
     }
@@ -141,9 +297,17 @@ impl Display for LiteralData {
         match &self {
             Self::Bool(bool_value) => write!(f, "{}", bool_value),
             Self::String(string_value) => write!(f, "{}", string_value),
-            Self::Number(number_value) => write!(f, "{}", number_value),
-            Self::None => write!(f, "None"),
+            Self::Integer(integer_value) => write!(f, "{}", integer_value),
+            Self::Float(float_value) => write!(f, "{:?}", float_value),
+            Self::None => write!(f, "null"),
             Self::Function(function_value) => write!(f, "{}", function_value),
+            Self::NativeFunction(function_value) => write!(f, "{}", function_value),
+            Self::Module(module_value) => write!(f, "{}", module_value),
+            Self::Range(range_value) => write!(f, "{}", range_value),
+            Self::List(list_value) => {
+                let items: Vec<String> = list_value.iter().map(|item| item.to_string()).collect();
+                write!(f, "[{}]", items.join(", "))
+            }
         }
     }
 }
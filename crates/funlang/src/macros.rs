@@ -1,25 +1,36 @@
 #[macro_export]
-macro_rules! token_lit_number {
+macro_rules! token_lit_integer {
     ($value:expr) => {
-        crate::token::Token::new(TokenType::Number)
-            .set_literal_data(crate::literal::LiteralData::Number($value))
+        $crate::token::Token::new(TokenType::Number)
+            .set_literal_data($crate::literal::LiteralData::Integer($value))
+    };
+}
+
+#[macro_export]
+macro_rules! token_lit_float {
+    ($value:expr) => {
+        $crate::token::Token::new(TokenType::Number)
+            .set_literal_data($crate::literal::LiteralData::Float($value))
     };
 }
 
 #[macro_export]
 macro_rules! token_lit_string {
     ($value:expr) => {
-        crate::token::Token::new(TokenType::String)
-            .set_literal_data(crate::literal::LiteralData::String($value))
+        $crate::token::Token::new(TokenType::String)
+            .set_literal_data($crate::literal::LiteralData::String($value))
     };
 }
 
 #[macro_export]
 macro_rules! parse_string_to_num {
     ($value:expr, $error:expr) => {
-        match $value.parse::<f32>() {
-            Ok(parsed_value) => Ok(parsed_value),
-            Err(_) => Err($error),
+        match $value.parse::<i64>() {
+            Ok(parsed_value) => Ok($crate::literal::LiteralData::Integer(parsed_value)),
+            Err(_) => match $value.parse::<f64>() {
+                Ok(parsed_value) => Ok($crate::literal::LiteralData::Float(parsed_value)),
+                Err(_) => Err($error),
+            },
         }
     };
 }
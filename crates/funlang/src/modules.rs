@@ -0,0 +1,396 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Debug, Display},
+    fs, io, mem,
+    path::{Component, Path, PathBuf},
+    rc::Rc,
+};
+
+use funlang_error::ErrorCascade;
+
+use crate::{
+    ast::{
+        stmt::Stmt,
+        traits::{Evaluable, Executable},
+    },
+    environment::Environment,
+    error,
+    errors::InterpreterError,
+    interpreter::{has_strict_pragma, type_check},
+    lexer::Lexer,
+    literal::LiteralData,
+    parser::Parser,
+};
+
+pub trait ModuleResolver {
+    fn locate(&self, path: &str, importer: Option<&str>) -> io::Result<String>;
+    fn resolve(&self, location: &str) -> io::Result<String>;
+}
+
+fn importer_directory(importer: Option<&str>) -> &Path {
+    importer
+        .and_then(|importer| Path::new(importer).parent())
+        .unwrap_or(Path::new(""))
+}
+
+pub struct FileResolver {
+    root: PathBuf,
+}
+
+impl Default for FileResolver {
+    fn default() -> Self {
+        Self::new(".")
+    }
+}
+
+impl FileResolver {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl ModuleResolver for FileResolver {
+    fn locate(&self, path: &str, importer: Option<&str>) -> io::Result<String> {
+        let directory = self.root.join(importer_directory(importer));
+        let location = fs::canonicalize(directory.join(path))?;
+        Ok(location.to_string_lossy().into_owned())
+    }
+
+    fn resolve(&self, location: &str) -> io::Result<String> {
+        fs::read_to_string(location)
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryResolver {
+    modules: HashMap<String, String>,
+}
+
+impl MemoryResolver {
+    pub fn new() -> Self {
+        Self {
+            modules: HashMap::new(),
+        }
+    }
+
+    pub fn set_module(mut self, path: &str, source: &str) -> Self {
+        self.modules.insert(path.to_owned(), source.to_owned());
+        self
+    }
+}
+
+impl ModuleResolver for MemoryResolver {
+    fn locate(&self, path: &str, importer: Option<&str>) -> io::Result<String> {
+        let mut location = PathBuf::new();
+        for component in importer_directory(importer).join(path).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    location.pop();
+                }
+                component => location.push(component),
+            }
+        }
+        let location = location.to_string_lossy().into_owned();
+        if self.modules.contains_key(&location) {
+            Ok(location)
+        } else {
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        }
+    }
+
+    fn resolve(&self, location: &str) -> io::Result<String> {
+        self.modules
+            .get(location)
+            .cloned()
+            .ok_or(io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+pub struct Module {
+    path: String,
+    environment: Environment,
+    exports: Vec<String>,
+}
+
+impl Module {
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn exports(&self) -> &[String] {
+        &self.exports
+    }
+
+    pub fn get(&self, name: &str) -> Result<LiteralData, ErrorCascade<InterpreterError>> {
+        let undefined_export = || {
+            error!(InterpreterError::UndefinedExport(
+                self.path.clone(),
+                name.to_owned()
+            ))
+        };
+        if !self.exports.iter().any(|export| export == name) {
+            return Err(undefined_export());
+        }
+        let mut environment = self.environment.clone();
+        environment
+            .variable(name)
+            .ok_or_else(undefined_export)?
+            .evaluate(&mut environment)
+    }
+}
+
+impl Debug for Module {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Module")
+            .field("path", &self.path)
+            .field("exports", &self.exports)
+            .finish()
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<module {}>", self.path)
+    }
+}
+
+fn exported_names(statements: &[Stmt]) -> Vec<String> {
+    statements
+        .iter()
+        .flat_map(|statement| match statement {
+            Stmt::Export(export_statement) => match &export_statement.declaration {
+                Stmt::Variable(variable_statement) => {
+                    variable_statement.name.lexeme.clone().into_iter().collect()
+                }
+                Stmt::Destructure(destructure_statement) => destructure_statement.pattern.names(),
+                Stmt::Function(function_statement) => {
+                    function_statement.name.lexeme.clone().into_iter().collect()
+                }
+                _ => vec![],
+            },
+            _ => vec![],
+        })
+        .collect()
+}
+
+struct LoaderState {
+    resolver: Box<dyn ModuleResolver>,
+    cache: HashMap<String, Rc<Module>>,
+    loading: Vec<(String, String)>,
+}
+
+#[derive(Clone)]
+pub struct ModuleLoader(Rc<RefCell<LoaderState>>);
+
+impl Default for ModuleLoader {
+    fn default() -> Self {
+        Self::new(FileResolver::default())
+    }
+}
+
+impl Debug for ModuleLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.0.borrow();
+        f.debug_struct("ModuleLoader")
+            .field("cache", &state.cache.keys().collect::<Vec<&String>>())
+            .field("loading", &state.loading)
+            .finish()
+    }
+}
+
+impl ModuleLoader {
+    pub fn new<R: ModuleResolver + 'static>(resolver: R) -> Self {
+        Self(Rc::new(RefCell::new(LoaderState {
+            resolver: Box::new(resolver),
+            cache: HashMap::new(),
+            loading: Vec::new(),
+        })))
+    }
+
+    pub fn clear(&self) {
+        let cache = mem::take(&mut self.0.borrow_mut().cache);
+        drop(cache);
+    }
+
+    fn parse(
+        &self,
+        path: &str,
+        location: &str,
+    ) -> Result<Vec<Stmt>, ErrorCascade<InterpreterError>> {
+        let source = self
+            .0
+            .borrow()
+            .resolver
+            .resolve(location)
+            .map_err(|error| {
+                error!(InterpreterError::UnresolvedModule(
+                    path.to_owned(),
+                    error.to_string()
+                ))
+            })?;
+        let module_exception = || error!(InterpreterError::ModuleException(path.to_owned()));
+
+        let tokens = Lexer::new()
+            .tokenize(&source)
+            .map_err(|error| module_exception().set_embedded_error(Box::new(error)))?;
+        let statements = Parser::new()
+            .set_module(true)
+            .parse(tokens)
+            .map_err(|error| module_exception().set_embedded_error(Box::new(error)))?;
+        type_check(&statements)
+            .map_err(|error| module_exception().set_embedded_error(Box::new(error)))?;
+
+        Ok(statements)
+    }
+
+    fn execute(
+        &self,
+        path: &str,
+        location: &str,
+        environment: &Environment,
+    ) -> Result<Module, ErrorCascade<InterpreterError>> {
+        let statements = self.parse(path, location)?;
+
+        let mut module_environment = environment.root().create_scope();
+        module_environment.set_module_location(Some(location.to_owned()));
+        if has_strict_pragma(&statements) {
+            module_environment.set_strict(true);
+        }
+        for statement in &statements {
+            statement
+                .execute(&mut module_environment)
+                .map_err(|error| {
+                    error!(InterpreterError::ModuleException(path.to_owned()))
+                        .set_embedded_error(Box::new(error))
+                })?;
+        }
+
+        Ok(Module {
+            path: path.to_owned(),
+            environment: module_environment,
+            exports: exported_names(&statements),
+        })
+    }
+
+    pub fn load(
+        &self,
+        path: &str,
+        environment: &Environment,
+    ) -> Result<Rc<Module>, ErrorCascade<InterpreterError>> {
+        let importer = environment.module_location();
+        let location = self
+            .0
+            .borrow()
+            .resolver
+            .locate(path, importer.as_deref())
+            .map_err(|error| {
+                error!(InterpreterError::UnresolvedModule(
+                    path.to_owned(),
+                    error.to_string()
+                ))
+            })?;
+
+        if let Some(module) = self.0.borrow().cache.get(&location) {
+            return Ok(module.clone());
+        }
+
+        {
+            let mut state = self.0.borrow_mut();
+            if state
+                .loading
+                .iter()
+                .any(|(loading_location, _)| *loading_location == location)
+            {
+                let mut cycle: Vec<String> = state
+                    .loading
+                    .iter()
+                    .map(|(_, loading_path)| loading_path.clone())
+                    .collect();
+                cycle.push(path.to_owned());
+                return Err(error!(InterpreterError::CyclicImport(cycle.join(" -> "))));
+            }
+            state.loading.push((location.clone(), path.to_owned()));
+        }
+
+        let module = self.execute(path, &location, environment);
+        self.0.borrow_mut().loading.pop();
+
+        let module = Rc::new(module?);
+        self.0.borrow_mut().cache.insert(location, module.clone());
+        Ok(module)
+    }
+}
+
+#[cfg(test)]
+mod modules_tests {
+    use super::*;
+
+    fn module_environment(resolver: MemoryResolver) -> Environment {
+        let mut environment = Environment::new();
+        environment.set_loader(ModuleLoader::new(resolver));
+        environment
+    }
+
+    #[test]
+    fn loads_exported_values() {
+        let environment = module_environment(
+            MemoryResolver::new().set_module("math.fun", "export let answer = 42; let hidden = 1;"),
+        );
+
+        let module = environment.loader().load("math.fun", &environment).unwrap();
+        assert_eq!(module.get("answer").unwrap(), LiteralData::Integer(42));
+        assert!(module.get("hidden").is_err());
+    }
+
+    #[test]
+    fn caches_loaded_modules() {
+        let environment = module_environment(
+            MemoryResolver::new().set_module("math.fun", "export let answer = 42;"),
+        );
+
+        let loader = environment.loader();
+        let first = loader.load("math.fun", &environment).unwrap();
+        let second = loader.load("math.fun", &environment).unwrap();
+        assert!(Rc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn detects_import_cycles() {
+        let environment = module_environment(
+            MemoryResolver::new()
+                .set_module("a.fun", "import \"b.fun\" as b;")
+                .set_module("b.fun", "import \"a.fun\" as a;"),
+        );
+
+        let error = environment
+            .loader()
+            .load("a.fun", &environment)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "[line 1:8 - CyclicImport] import cycle detected: a.fun -> b.fun -> a.fun"
+        );
+    }
+
+    #[test]
+    fn resolves_imports_relative_to_the_importer() {
+        let environment = module_environment(
+            MemoryResolver::new()
+                .set_module("math.fun", "export let answer = 42;")
+                .set_module(
+                    "lib/answer.fun",
+                    "import \"../math.fun\" as math; export let answer = math.answer;",
+                ),
+        );
+
+        let loader = environment.loader();
+        let nested = loader.load("./lib/answer.fun", &environment).unwrap();
+        assert_eq!(nested.get("answer").unwrap(), LiteralData::Integer(42));
+
+        let first = loader.load("math.fun", &environment).unwrap();
+        let second = loader.load("./lib/../math.fun", &environment).unwrap();
+        assert!(Rc::ptr_eq(&first, &second));
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    io::{self, Write},
+    rc::Rc,
+};
+
+enum Sink {
+    Writer(Box<dyn Write>),
+    Callback(Box<dyn FnMut(&str)>),
+}
+
+#[derive(Clone)]
+pub struct Output(Rc<RefCell<Sink>>);
+
+impl Output {
+    pub fn writer<W: Write + 'static>(writer: W) -> Self {
+        Self(Rc::new(RefCell::new(Sink::Writer(Box::new(writer)))))
+    }
+
+    pub fn callback<F: FnMut(&str) + 'static>(callback: F) -> Self {
+        Self(Rc::new(RefCell::new(Sink::Callback(Box::new(callback)))))
+    }
+
+    pub fn sink() -> Self {
+        Self::writer(io::sink())
+    }
+
+    pub fn print_line(&self, line: &str) -> io::Result<()> {
+        match &mut *self.0.borrow_mut() {
+            Sink::Writer(writer) => {
+                writeln!(writer, "{}", line)?;
+                writer.flush()
+            }
+            Sink::Callback(callback) => {
+                callback(line);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::writer(io::stdout())
+    }
+}
+
+impl Debug for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self.0.borrow() {
+            Sink::Writer(_) => write!(f, "Output(Writer)"),
+            Sink::Callback(_) => write!(f, "Output(Callback)"),
+        }
+    }
+}
@@ -3,23 +3,38 @@ use funlang_error::ErrorCascade;
 use crate::{
     ast::{
         expr::{
-            AssignExpr, BinaryExpr, CallExpr, Expr, GroupingExpr, LiteralExpr, LogicalExpr,
-            UnaryExpr, VariableExpr,
+            Argument, AssignExpr, BinaryExpr, BlockExpr, CallExpr, CompoundAssignExpr,
+            ConditionalExpr, Expr, GetExpr, GroupingExpr, IndexExpr, InterpolationExpr,
+            LiteralExpr, LogicalExpr, MatchArm, MatchExpr, OptionalChainExpr, PartialExpr, Pattern,
+            RangeExpr, UnaryExpr, VariableExpr,
         },
         stmt::{
-            BlockStmt, ExpressionStmt, FunctionStmt, IfStmt, PrintStmt, Stmt, VariableStmt,
-            WhileStmt,
+            BlockStmt, BreakStmt, ContinueStmt, DestructurePattern, DestructureStmt, ExportStmt,
+            ExpressionStmt, ForInStmt, FunctionStmt, IfStmt, ImportStmt, Parameter, PrintStmt,
+            ReturnStmt, Stmt, TestStmt, ValueStmt, VariableStmt, WhileStmt,
         },
     },
     error,
     errors::ParserError,
     literal::LiteralData,
+    range::Range,
     token::{Token, TokenType},
+    types::Type,
 };
 
 pub struct Parser {
     tokens: Option<Vec<Token>>,
     crawled_index: usize,
+    function_depth: usize,
+    loop_labels: Vec<Option<String>>,
+    block_depth: usize,
+    is_module: bool,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Parser {
@@ -27,9 +42,18 @@ impl Parser {
         Self {
             tokens: None,
             crawled_index: 0,
+            function_depth: 0,
+            loop_labels: vec![],
+            block_depth: 0,
+            is_module: false,
         }
     }
 
+    pub fn set_module(mut self, is_module: bool) -> Self {
+        self.is_module = is_module;
+        self
+    }
+
     fn unwrap_tokens(&self) -> Result<&Vec<Token>, ErrorCascade<ParserError>> {
         self.tokens
             .as_ref()
@@ -38,6 +62,9 @@ impl Parser {
 
     fn clear_state(&mut self) {
         self.crawled_index = 0;
+        self.function_depth = 0;
+        self.loop_labels.clear();
+        self.block_depth = 0;
     }
 
     fn is_at_end(&self) -> Result<bool, ErrorCascade<ParserError>> {
@@ -71,6 +98,13 @@ impl Parser {
         }
     }
 
+    fn peek_next(&self) -> Result<Token, ErrorCascade<ParserError>> {
+        match self.unwrap_tokens()?.get(self.crawled_index + 1) {
+            Some(token) => Ok(token.clone()),
+            None => Err(error!(ParserError::InvalidTokenIndex)),
+        }
+    }
+
     fn advance(&mut self) -> Result<(), ErrorCascade<ParserError>> {
         if !self.is_at_end()? {
             self.crawled_index += 1;
@@ -87,16 +121,14 @@ impl Parser {
     }
 
     fn r#match(&mut self, token_types: Vec<TokenType>) -> Result<bool, ErrorCascade<ParserError>> {
-        let mut result = false;
-
         for token_type in token_types {
             if self.check(token_type)? {
                 self.advance()?;
-                result = true;
+                return Ok(true);
             }
         }
 
-        Ok(result)
+        Ok(false)
     }
 
     fn primary(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
@@ -114,12 +146,26 @@ impl Parser {
             })))
         } else if self.r#match(vec![TokenType::Number, TokenType::String])? {
             let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
-            Ok(Expr::Literal(Box::new(LiteralExpr {
-                literal: self
-                    .previous()?
-                    .literal_data
-                    .ok_or(error!(ParserError::InvalidLiteralData).set_span(span.into()))?,
-            })))
+            let literal = self
+                .previous()?
+                .literal_data
+                .ok_or(error!(ParserError::InvalidLiteralData).set_span(span.into()))?;
+            if matches!(literal, LiteralData::Integer(i64::MIN)) {
+                return Err(Self::integer_out_of_range(self.previous()?));
+            }
+            Ok(Expr::Literal(Box::new(LiteralExpr { literal })))
+        } else if self.r#match(vec![TokenType::Interpolation])? {
+            self.interpolation()
+        } else if self.r#match(vec![TokenType::Match])? {
+            self.match_expression()
+        } else if self.r#match(vec![TokenType::LeftBrace])? {
+            let keyword = self.previous()?;
+            let body = self.block_statement()?;
+            Ok(Expr::Block(Box::new(BlockExpr { keyword, body })))
+        } else if self.r#match(vec![TokenType::If])? {
+            let keyword = self.previous()?;
+            let body = self.if_statement()?;
+            Ok(Expr::Block(Box::new(BlockExpr { keyword, body })))
         } else if self.r#match(vec![TokenType::LeftParen])? {
             let expr = self.expression()?;
             let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
@@ -138,57 +184,326 @@ impl Parser {
         }
     }
 
-    fn finish_call(&mut self, callee: Expr) -> Result<Expr, ErrorCascade<ParserError>> {
-        let mut arguments: Vec<Expr> = Vec::new();
+    fn integer_out_of_range(token: Token) -> ErrorCascade<ParserError> {
+        let error = error!(ParserError::IntegerOutOfRange(
+            i64::MIN.unsigned_abs().to_string()
+        ));
+        match token.span {
+            Some(span) => error.set_span(span.into()),
+            None => error,
+        }
+    }
+
+    fn pattern_literal(&mut self) -> Result<Option<LiteralData>, ErrorCascade<ParserError>> {
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        let is_negative = self.r#match(vec![TokenType::Minus])?;
+
+        if !is_negative && self.r#match(vec![TokenType::True, TokenType::False, TokenType::None])? {
+            return Ok(Some(match self.previous()?.token_type {
+                TokenType::True => LiteralData::Bool(true),
+                TokenType::False => LiteralData::Bool(false),
+                _ => LiteralData::None,
+            }));
+        }
+
+        let literal_types = if is_negative {
+            vec![TokenType::Number]
+        } else {
+            vec![TokenType::Number, TokenType::String]
+        };
+        if !self.r#match(literal_types)? {
+            return match is_negative {
+                true => Err(error!(ParserError::ExpectedPattern).set_span(span.into())),
+                false => Ok(None),
+            };
+        }
+
+        let literal = self
+            .previous()?
+            .literal_data
+            .ok_or(error!(ParserError::InvalidLiteralData).set_span(span.clone().into()))?;
+        match (is_negative, literal) {
+            (false, LiteralData::Integer(i64::MIN)) => {
+                Err(Self::integer_out_of_range(self.previous()?))
+            }
+            (true, LiteralData::Integer(integer_value)) => {
+                Ok(Some(LiteralData::Integer(integer_value.wrapping_neg())))
+            }
+            (true, LiteralData::Float(float_value)) => Ok(Some(LiteralData::Float(-float_value))),
+            (_, literal) => Ok(Some(literal)),
+        }
+    }
+
+    fn pattern(&mut self) -> Result<Pattern, ErrorCascade<ParserError>> {
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+
+        if self.r#match(vec![TokenType::Identifier])? {
+            let name = self.previous()?;
+            return Ok(match name.lexeme.as_deref() {
+                Some("_") => Pattern::Wildcard,
+                _ => Pattern::Binding(name),
+            });
+        }
+
+        let Some(literal) = self.pattern_literal()? else {
+            return Err(error!(ParserError::ExpectedPattern).set_span(span.into()));
+        };
+
+        if !self.r#match(vec![TokenType::DotDot, TokenType::DotDotEqual])? {
+            return Ok(Pattern::Literal(literal));
+        }
+        let inclusive = self.previous()?.token_type == TokenType::DotDotEqual;
+        let end = self.pattern_literal()?;
+
+        match (literal, end) {
+            (LiteralData::Integer(start), Some(LiteralData::Integer(end))) => {
+                Ok(Pattern::Range(Range::new(start, end, inclusive)))
+            }
+            _ => Err(error!(ParserError::InvalidRangePattern).set_span(span.into())),
+        }
+    }
+
+    fn match_expression(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+        let subject = self.expression()?;
+
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        self.consume(
+            TokenType::LeftBrace,
+            error!(ParserError::ExpectedMatchBlock).set_span(span.into()),
+        )?;
+
+        let mut arms: Vec<MatchArm> = vec![];
+        while !self.check(TokenType::RightBrace)? && !self.is_at_end()? {
+            let pattern = self.pattern()?;
+            let guard = if self.r#match(vec![TokenType::If])? {
+                Some(self.expression()?)
+            } else {
+                None
+            };
+
+            let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+            self.consume(
+                TokenType::FatArrow,
+                error!(ParserError::ExpectedFatArrow).set_span(span.into()),
+            )?;
+            let body = self.expression()?;
+            arms.push(MatchArm {
+                pattern,
+                guard,
+                body,
+            });
+
+            if !self.r#match(vec![TokenType::Comma])? {
+                break;
+            }
+        }
+
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        self.consume(
+            TokenType::RightBrace,
+            error!(ParserError::UnterminatedMatch).set_span(span.into()),
+        )?;
+
+        Ok(Expr::Match(Box::new(MatchExpr {
+            keyword,
+            subject,
+            arms,
+        })))
+    }
+
+    fn interpolation(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut parts: Vec<Expr> = vec![];
+
+        loop {
+            let literal = self
+                .previous()?
+                .literal_data
+                .ok_or(error!(ParserError::InvalidLiteralData))?;
+            parts.push(Expr::Literal(Box::new(LiteralExpr { literal })));
+
+            if self.previous()?.token_type == TokenType::String {
+                break;
+            }
+
+            parts.push(self.expression()?);
+
+            let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+            if !self.r#match(vec![TokenType::Interpolation, TokenType::String])? {
+                Err(error!(ParserError::UnterminatedInterpolation).set_span(span.into()))?;
+            }
+        }
+
+        Ok(Expr::Interpolation(Box::new(InterpolationExpr { parts })))
+    }
+
+    fn finish_call(
+        &mut self,
+        callee: Expr,
+        optional: bool,
+    ) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut arguments: Vec<Argument> = Vec::new();
 
         if !self.check(TokenType::RightParen)? {
             'arguments: loop {
                 if arguments.len() >= 255 {
                     Err(error!(ParserError::MaxArguments))?;
                 }
-                arguments.push(self.expression()?);
+                let name = if self.check(TokenType::Identifier)?
+                    && self.peek_next()?.token_type == TokenType::Colon
+                {
+                    let name = self.peek()?;
+                    self.advance()?;
+                    self.advance()?;
+                    Some(name)
+                } else {
+                    None
+                };
+
+                let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+                let value = self.expression()?;
+                if name.is_none()
+                    && arguments
+                        .iter()
+                        .any(|argument: &Argument| argument.name.is_some())
+                {
+                    Err(error!(ParserError::PositionalAfterNamed).set_span(span.into()))?;
+                }
+                arguments.push(Argument { name, value });
                 if !self.r#match(vec![TokenType::Comma])? {
                     break 'arguments;
                 }
             }
         };
 
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
         let paren = self.consume(
             TokenType::RightParen,
-            error!(ParserError::ExpectedArguments),
+            error!(ParserError::ExpectedArguments).set_span(span.into()),
         )?;
 
+        if arguments.iter().any(Argument::is_placeholder) {
+            return Ok(Expr::Partial(Box::new(PartialExpr {
+                callee,
+                paren,
+                arguments,
+            })));
+        }
+
         Ok(Expr::Call(Box::new(CallExpr {
             callee,
             paren,
             arguments,
+            optional,
         })))
     }
 
     fn call(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
         let mut expr = self.primary()?;
+        let mut is_optional_chain = false;
+
+        loop {
+            if self.r#match(vec![TokenType::LeftParen])? {
+                expr = self.finish_call(expr, false)?;
+            } else if self.r#match(vec![TokenType::LeftBracket])? {
+                let bracket = self.previous()?;
+                let index = self.expression()?;
+                let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+                self.consume(
+                    TokenType::RightBracket,
+                    error!(ParserError::UnterminatedIndex).set_span(span.into()),
+                )?;
+                expr = Expr::Index(Box::new(IndexExpr {
+                    object: expr,
+                    bracket,
+                    index,
+                }));
+            } else if self.r#match(vec![TokenType::Dot])? {
+                let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+                let name = self.consume(
+                    TokenType::Identifier,
+                    error!(ParserError::ExpectedPropertyName).set_span(span.into()),
+                )?;
+                expr = Expr::Get(Box::new(GetExpr {
+                    object: expr,
+                    name,
+                    optional: false,
+                }));
+            } else if self.r#match(vec![TokenType::QuestionDot])? {
+                is_optional_chain = true;
+                if self.r#match(vec![TokenType::LeftParen])? {
+                    expr = self.finish_call(expr, true)?;
+                    continue;
+                }
+                let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+                let name = self.consume(
+                    TokenType::Identifier,
+                    error!(ParserError::ExpectedPropertyName).set_span(span.into()),
+                )?;
+                expr = Expr::Get(Box::new(GetExpr {
+                    object: expr,
+                    name,
+                    optional: true,
+                }));
+            } else {
+                break;
+            }
+        }
+
+        if is_optional_chain {
+            expr = Expr::OptionalChain(Box::new(OptionalChainExpr { expression: expr }));
+        }
+
+        Ok(expr)
+    }
+
+    fn power(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let expr = self.call()?;
 
-        while self.r#match(vec![TokenType::LeftParen])? {
-            expr = self.finish_call(expr)?;
+        if self.r#match(vec![TokenType::StarStar])? {
+            let operator: Token = self.previous()?;
+            let right: Expr = self.unary()?;
+            return Ok(Expr::Binary(Box::new(BinaryExpr {
+                left: expr,
+                operator,
+                right,
+            })));
         }
 
         Ok(expr)
     }
 
     fn unary(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
-        if self.r#match(vec![TokenType::Bang, TokenType::Minus])? {
+        if self.r#match(vec![TokenType::Bang, TokenType::Minus, TokenType::Tilde])? {
             let operator = self.previous()?;
+            if operator.token_type == TokenType::Minus
+                && matches!(
+                    self.peek()?.literal_data,
+                    Some(LiteralData::Integer(i64::MIN))
+                )
+                && self.peek_next()?.token_type != TokenType::StarStar
+            {
+                self.advance()?;
+                return Ok(Expr::Literal(Box::new(LiteralExpr {
+                    literal: LiteralData::Integer(i64::MIN),
+                })));
+            }
             let right = self.unary()?;
             Ok(Expr::Unary(Box::new(UnaryExpr { operator, right })))
         } else {
-            self.call()
+            self.power()
         }
     }
 
     fn factor(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
         let mut expr = self.unary()?;
 
-        while self.r#match(vec![TokenType::Slash, TokenType::Star])? {
+        while self.r#match(vec![
+            TokenType::Slash,
+            TokenType::Star,
+            TokenType::Percent,
+            TokenType::Div,
+        ])? {
             let operator: Token = self.previous()?;
             let right: Expr = self.unary()?;
             expr = Expr::Binary(Box::new(BinaryExpr {
@@ -217,17 +532,98 @@ impl Parser {
         Ok(expr)
     }
 
-    fn comparison(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+    fn shift(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
         let mut expr = self.term()?;
 
+        while self.r#match(vec![TokenType::LessLess, TokenType::GreaterGreater])? {
+            let operator: Token = self.previous()?;
+            let right: Expr = self.term()?;
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: expr,
+                operator,
+                right,
+            }));
+        }
+
+        Ok(expr)
+    }
+
+    fn bitwise_and(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut expr = self.shift()?;
+
+        while self.r#match(vec![TokenType::Ampersand])? {
+            let operator: Token = self.previous()?;
+            let right: Expr = self.shift()?;
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: expr,
+                operator,
+                right,
+            }));
+        }
+
+        Ok(expr)
+    }
+
+    fn bitwise_xor(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut expr = self.bitwise_and()?;
+
+        while self.r#match(vec![TokenType::Caret])? {
+            let operator: Token = self.previous()?;
+            let right: Expr = self.bitwise_and()?;
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: expr,
+                operator,
+                right,
+            }));
+        }
+
+        Ok(expr)
+    }
+
+    fn bitwise_or(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut expr = self.bitwise_xor()?;
+
+        while self.r#match(vec![TokenType::Pipe])? {
+            let operator: Token = self.previous()?;
+            let right: Expr = self.bitwise_xor()?;
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: expr,
+                operator,
+                right,
+            }));
+        }
+
+        Ok(expr)
+    }
+
+    fn range(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let expr = self.bitwise_or()?;
+
+        if self.r#match(vec![TokenType::DotDot, TokenType::DotDotEqual])? {
+            let operator: Token = self.previous()?;
+            let end: Expr = self.bitwise_or()?;
+            return Ok(Expr::Range(Box::new(RangeExpr {
+                start: expr,
+                operator,
+                end,
+            })));
+        }
+
+        Ok(expr)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut expr = self.range()?;
+
         while self.r#match(vec![
             TokenType::Greater,
             TokenType::GreaterEqual,
             TokenType::Less,
             TokenType::LessEqual,
+            TokenType::In,
         ])? {
             let operator: Token = self.previous()?;
-            let right: Expr = self.term()?;
+            let right: Expr = self.range()?;
             expr = Expr::Binary(Box::new(BinaryExpr {
                 left: expr,
                 operator,
@@ -270,8 +666,82 @@ impl Parser {
         Ok(expr)
     }
 
+    fn conditional(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let condition = self.coalesce()?;
+
+        if self.r#match(vec![TokenType::Question])? {
+            let question = self.previous()?;
+            let then_branch = self.assignment()?;
+            let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+            self.consume(
+                TokenType::Colon,
+                error!(ParserError::ExpectedConditionalColon).set_span(span.into()),
+            )?;
+            let else_branch = self.conditional()?;
+            return Ok(Expr::Conditional(Box::new(ConditionalExpr {
+                condition,
+                question,
+                then_branch,
+                else_branch,
+            })));
+        }
+
+        Ok(condition)
+    }
+
+    fn coalesce(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut expr: Expr = self.logical()?;
+
+        while self.r#match(vec![TokenType::QuestionQuestion])? {
+            let operator = self.previous()?;
+            let right = self.logical()?;
+            expr = Expr::Logical(Box::new(LogicalExpr {
+                left: expr,
+                operator,
+                right,
+            }))
+        }
+
+        Ok(expr)
+    }
+
+    fn pipe(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
+        let mut expr = self.conditional()?;
+
+        while self.r#match(vec![TokenType::PipeGreater])? {
+            let operator = self.previous()?;
+            let target = self.call()?;
+            let argument = Argument {
+                name: None,
+                value: expr,
+            };
+            expr = match target {
+                Expr::Call(mut call_expr) => {
+                    call_expr.arguments.insert(0, argument);
+                    Expr::Call(call_expr)
+                }
+                Expr::OptionalChain(mut optional_chain_expr)
+                    if matches!(optional_chain_expr.expression, Expr::Call(_)) =>
+                {
+                    if let Expr::Call(call_expr) = &mut optional_chain_expr.expression {
+                        call_expr.arguments.insert(0, argument);
+                    }
+                    Expr::OptionalChain(optional_chain_expr)
+                }
+                callee => Expr::Call(Box::new(CallExpr {
+                    callee,
+                    paren: operator,
+                    arguments: vec![argument],
+                    optional: false,
+                })),
+            };
+        }
+
+        Ok(expr)
+    }
+
     fn assignment(&mut self) -> Result<Expr, ErrorCascade<ParserError>> {
-        let expr = self.logical()?;
+        let expr = self.pipe()?;
 
         if self.r#match(vec![TokenType::Equal])? {
             let value = self.assignment()?;
@@ -283,6 +753,32 @@ impl Parser {
                 }
                 _ => Err(error!(ParserError::InvalidAssignmentTarget)),
             }
+        } else if self.r#match(vec![
+            TokenType::PlusEqual,
+            TokenType::MinusEqual,
+            TokenType::StarEqual,
+            TokenType::SlashEqual,
+            TokenType::PercentEqual,
+        ])? {
+            let compound_operator = self.previous()?;
+            let operator_type = compound_operator
+                .token_type
+                .compound_operator()
+                .ok_or(error!(ParserError::InvalidAssignmentTarget))?;
+            let value = self.assignment()?;
+
+            match expr {
+                Expr::Variable(variable_expression) => {
+                    let mut operator = compound_operator;
+                    operator.token_type = operator_type;
+                    Ok(Expr::CompoundAssign(Box::new(CompoundAssignExpr {
+                        name: variable_expression.name,
+                        operator,
+                        value,
+                    })))
+                }
+                _ => Err(error!(ParserError::InvalidAssignmentTarget)),
+            }
         } else {
             Ok(expr)
         }
@@ -294,6 +790,14 @@ impl Parser {
 
     fn expression_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
         let expression = self.expression()?;
+        if self.block_depth > 0 && self.check(TokenType::RightBrace)? {
+            return Ok(Stmt::Value(Box::new(ValueStmt { expression })));
+        }
+        if matches!(expression, Expr::Block(_) | Expr::Match(_))
+            && !self.check(TokenType::Semicolon)?
+        {
+            return Ok(Stmt::Expression(Box::new(ExpressionStmt { expression })));
+        }
         self.consume(
             TokenType::Semicolon,
             error!(ParserError::UnterminatedStatement),
@@ -301,6 +805,55 @@ impl Parser {
         Ok(Stmt::Expression(Box::new(ExpressionStmt { expression })))
     }
 
+    fn type_annotation(&mut self) -> Result<Type, ErrorCascade<ParserError>> {
+        let token = self.peek()?;
+        let span = token.span.clone().ok_or(error!(ParserError::MissingSpan))?;
+        let name = match token.token_type {
+            TokenType::Identifier => token.lexeme.unwrap_or_default(),
+            TokenType::None => "null".to_string(),
+            TokenType::Fn => "function".to_string(),
+            _ => Err(error!(ParserError::ExpectedType).set_span(span.clone().into()))?,
+        };
+        self.advance()?;
+        Type::from_name(&name).ok_or(error!(ParserError::UnknownType(name)).set_span(span.into()))
+    }
+
+    fn optional_type_annotation(
+        &mut self,
+        delimiter: TokenType,
+    ) -> Result<Option<Type>, ErrorCascade<ParserError>> {
+        if self.r#match(vec![delimiter])? {
+            Ok(Some(self.type_annotation()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn return_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+        let span = keyword
+            .span
+            .clone()
+            .ok_or(error!(ParserError::MissingSpan))?;
+
+        if self.function_depth == 0 {
+            Err(error!(ParserError::ReturnOutsideFunction).set_span(span.into()))?;
+        }
+
+        let value = if !self.check(TokenType::Semicolon)? {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        self.consume(
+            TokenType::Semicolon,
+            error!(ParserError::UnterminatedStatement),
+        )?;
+
+        Ok(Stmt::Return(Box::new(ReturnStmt { keyword, value })))
+    }
+
     fn print_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
         let expression = self.expression()?;
         self.consume(
@@ -310,12 +863,31 @@ impl Parser {
         Ok(Stmt::Print(Box::new(PrintStmt { expression })))
     }
 
+    fn expression_body(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let expression = self.expression()?;
+        self.consume(
+            TokenType::Semicolon,
+            error!(ParserError::UnterminatedStatement),
+        )?;
+        Ok(Stmt::Block(Box::new(BlockStmt {
+            statements: vec![Stmt::Value(Box::new(ValueStmt { expression }))],
+        })))
+    }
+
     fn block_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
         let mut statements: Vec<Stmt> = vec![];
 
+        self.block_depth += 1;
         while !self.check(TokenType::RightBrace)? && !self.is_at_end()? {
-            statements.push(self.declaration()?)
+            match self.declaration() {
+                Ok(statement) => statements.push(statement),
+                Err(error) => {
+                    self.block_depth -= 1;
+                    return Err(error);
+                }
+            }
         }
+        self.block_depth -= 1;
 
         self.consume(
             TokenType::RightBrace,
@@ -345,7 +917,15 @@ impl Parser {
         })))
     }
 
-    fn while_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+    fn loop_body(&mut self, label: &Option<Token>) -> Result<Stmt, ErrorCascade<ParserError>> {
+        self.loop_labels
+            .push(label.as_ref().and_then(|label| label.lexeme.clone()));
+        let body = self.block_statement();
+        self.loop_labels.pop();
+        body
+    }
+
+    fn while_statement(&mut self, label: Option<Token>) -> Result<Stmt, ErrorCascade<ParserError>> {
         let condition = self.expression()?;
 
         self.consume(
@@ -353,16 +933,50 @@ impl Parser {
             error!(ParserError::ExpectedWhileBlock),
         )?;
 
-        let body = self.block_statement()?;
+        let body = self.loop_body(&label)?;
+
+        Ok(Stmt::While(Box::new(WhileStmt {
+            condition,
+            body,
+            increment: None,
+            label,
+        })))
+    }
+
+    fn for_in_statement(
+        &mut self,
+        label: Option<Token>,
+    ) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let name = self.consume(
+            TokenType::Identifier,
+            error!(ParserError::ExpectedIdentifier),
+        )?;
+        let keyword = self.consume(TokenType::In, error!(ParserError::ExpectedIn))?;
+
+        let iterable = self.expression()?;
+
+        self.consume(TokenType::LeftBrace, error!(ParserError::ExpectedForBlock))?;
 
-        Ok(Stmt::While(Box::new(WhileStmt { condition, body })))
+        let body = self.loop_body(&label)?;
+
+        Ok(Stmt::ForIn(Box::new(ForInStmt {
+            name,
+            keyword,
+            iterable,
+            body,
+            label,
+        })))
     }
 
-    fn for_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+    fn for_statement(&mut self, label: Option<Token>) -> Result<Stmt, ErrorCascade<ParserError>> {
+        if self.check(TokenType::Identifier)? && self.peek_next()?.token_type == TokenType::In {
+            return self.for_in_statement(label);
+        }
+
         let for_initializer = if self.r#match(vec![TokenType::Let])? {
-            Some(self.var_declaration()?)
+            self.var_declaration()?
         } else {
-            Some(self.expression_statement()?)
+            self.expression_statement()?
         };
 
         let for_condition = self.expression()?;
@@ -376,74 +990,247 @@ impl Parser {
 
         self.consume(TokenType::LeftBrace, error!(ParserError::ExpectedForBlock))?;
 
-        let for_body = self.block_statement()?;
+        let for_body = self.loop_body(&label)?;
+
+        Ok(Stmt::Block(Box::new(BlockStmt {
+            statements: vec![
+                for_initializer,
+                Stmt::While(Box::new(WhileStmt {
+                    condition: for_condition,
+                    body: for_body,
+                    increment: Some(for_increment),
+                    label,
+                })),
+            ],
+        })))
+    }
+
+    fn labelled_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let label = self.previous()?;
+        self.advance()?;
 
-        let mut block_body: Vec<Stmt> = Vec::new();
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        if self.r#match(vec![TokenType::While])? {
+            self.while_statement(Some(label))
+        } else if self.r#match(vec![TokenType::For])? {
+            self.for_statement(Some(label))
+        } else {
+            Err(error!(ParserError::ExpectedLabelledLoop).set_span(span.into()))
+        }
+    }
 
-        if let Some(for_initializer) = for_initializer {
-            block_body.push(for_initializer);
+    fn loop_control_statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+        let keyword_name = match keyword.token_type {
+            TokenType::Break => "break",
+            _ => "continue",
         };
+        let span = keyword
+            .span
+            .clone()
+            .ok_or(error!(ParserError::MissingSpan))?;
+
+        if self.loop_labels.is_empty() {
+            Err(error!(ParserError::LoopControlOutsideLoop(
+                keyword_name.to_string()
+            ))
+            .set_span(span.clone().into()))?;
+        }
 
-        block_body.push(Stmt::While(Box::new(WhileStmt {
-            condition: for_condition,
-            body: Stmt::Block(Box::new(BlockStmt {
-                statements: Vec::from([
-                    Stmt::Expression(Box::new(ExpressionStmt {
-                        expression: for_increment,
-                    })),
-                    for_body,
-                ]),
-            })),
-        })));
+        let label = if self.r#match(vec![TokenType::Identifier])? {
+            let label = self.previous()?;
+            let name = label.lexeme.clone().unwrap_or_default();
+            if !self
+                .loop_labels
+                .iter()
+                .any(|loop_label| loop_label.as_ref() == Some(&name))
+            {
+                let label_span = label.span.clone().ok_or(error!(ParserError::MissingSpan))?;
+                Err(error!(ParserError::UndefinedLabel(name)).set_span(label_span.into()))?;
+            }
+            Some(label)
+        } else {
+            None
+        };
 
-        Ok(Stmt::Block(Box::new(BlockStmt {
-            statements: block_body,
-        })))
+        self.consume(
+            TokenType::Semicolon,
+            error!(ParserError::UnterminatedLoopControl(
+                keyword_name.to_string()
+            ))
+            .set_span(span.into()),
+        )?;
+
+        Ok(match keyword.token_type {
+            TokenType::Break => Stmt::Break(Box::new(BreakStmt { keyword, label })),
+            _ => Stmt::Continue(Box::new(ContinueStmt { keyword, label })),
+        })
+    }
+
+    fn declaration_doc(&self) -> Result<Option<String>, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+        if keyword.doc.is_some() || self.crawled_index < 2 {
+            return Ok(keyword.doc);
+        }
+        match self.unwrap_tokens()?.get(self.crawled_index - 2) {
+            Some(token) if token.token_type == TokenType::Export => Ok(token.doc.clone()),
+            _ => Ok(None),
+        }
     }
 
     fn function(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let doc = self.declaration_doc()?;
         let name = self.consume(
             TokenType::Identifier,
             error!(ParserError::ExpectedFunctionIdentifier),
         )?;
 
-        self.consume(TokenType::LeftParen, error!(ParserError::ExpectedArguments))?;
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        self.consume(
+            TokenType::LeftParen,
+            error!(ParserError::ExpectedArguments).set_span(span.into()),
+        )?;
 
-        let mut params: Vec<Token> = vec![];
+        let mut params: Vec<Parameter> = vec![];
         if !self.check(TokenType::RightParen)? {
             'parameters: loop {
                 if params.len() >= 255 {
                     Err(error!(ParserError::MaxArguments))?;
                 }
-                params.push(self.consume(
-                    TokenType::Identifier,
-                    error!(ParserError::ExpectedParameterIdentifier),
-                )?);
+                if self.r#match(vec![TokenType::DotDotDot])? {
+                    let name = self.consume(
+                        TokenType::Identifier,
+                        error!(ParserError::ExpectedParameterIdentifier),
+                    )?;
+                    if !self.check(TokenType::RightParen)? {
+                        let span = name.span.clone().ok_or(error!(ParserError::MissingSpan))?;
+                        Err(error!(ParserError::RestParameterNotLast(
+                            name.lexeme.clone().unwrap_or_default()
+                        ))
+                        .set_span(span.into()))?;
+                    }
+                    params.push(Parameter {
+                        name,
+                        type_annotation: None,
+                        pattern: None,
+                        default: None,
+                        variadic: true,
+                    });
+                    break 'parameters;
+                }
+
+                let (name, type_annotation, pattern) =
+                    if self.r#match(vec![TokenType::LeftBracket, TokenType::LeftBrace])? {
+                        let pattern = self.destructure_pattern()?;
+                        (pattern.token().clone(), None, Some(pattern))
+                    } else {
+                        let name = self.consume(
+                            TokenType::Identifier,
+                            error!(ParserError::ExpectedParameterIdentifier),
+                        )?;
+                        let type_annotation = self.optional_type_annotation(TokenType::Colon)?;
+                        (name, type_annotation, None)
+                    };
+                let default = if self.r#match(vec![TokenType::Equal])? {
+                    Some(self.expression()?)
+                } else {
+                    None
+                };
+                let param = Parameter {
+                    name,
+                    type_annotation,
+                    pattern,
+                    default,
+                    variadic: false,
+                };
+
+                if param.default.is_none() && params.iter().any(|param| param.default.is_some()) {
+                    let span = param
+                        .name
+                        .span
+                        .clone()
+                        .ok_or(error!(ParserError::MissingSpan))?;
+                    Err(error!(ParserError::MissingDefault(param.label())).set_span(span.into()))?;
+                }
+                params.push(param);
                 if !self.r#match(vec![TokenType::Comma])? {
                     break 'parameters;
                 }
             }
         };
 
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
         self.consume(
             TokenType::RightParen,
-            error!(ParserError::ExpectedArguments),
+            error!(ParserError::ExpectedArguments).set_span(span.into()),
         )?;
 
-        self.consume(
-            TokenType::LeftBrace,
-            error!(ParserError::ExpectedFunctionBlock),
-        )?;
+        let return_type = self.optional_type_annotation(TokenType::Arrow)?;
 
-        let body = self.block_statement()?;
+        let is_expression_body = self.r#match(vec![TokenType::Equal])?;
+        if !is_expression_body {
+            self.consume(
+                TokenType::LeftBrace,
+                error!(ParserError::ExpectedFunctionBlock),
+            )?;
+        }
+
+        self.function_depth += 1;
+        let loop_labels = std::mem::take(&mut self.loop_labels);
+        let body = match is_expression_body {
+            true => self.expression_body(),
+            false => self.block_statement(),
+        };
+        self.loop_labels = loop_labels;
+        self.function_depth -= 1;
 
         Ok(Stmt::Function(Box::new(FunctionStmt {
             name,
             params,
-            body,
+            return_type,
+            body: body?,
+            doc,
         })))
     }
 
+    fn is_test_declaration(&self) -> Result<bool, ErrorCascade<ParserError>> {
+        let token = self.peek()?;
+        Ok(token.token_type == TokenType::Identifier
+            && token.lexeme.as_deref() == Some("test")
+            && matches!(
+                self.peek_next()?.token_type,
+                TokenType::String
+                    | TokenType::Identifier
+                    | TokenType::Number
+                    | TokenType::LeftBrace
+            ))
+    }
+
+    fn test_declaration(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        self.advance()?;
+        let keyword = self.previous()?;
+        let span = keyword
+            .span
+            .clone()
+            .ok_or(error!(ParserError::MissingSpan))?;
+
+        if self.is_module || self.function_depth > 0 || self.block_depth > 0 {
+            Err(error!(ParserError::NestedTest).set_span(span.into()))?;
+        }
+
+        let name_span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        let name = self.consume(
+            TokenType::String,
+            error!(ParserError::ExpectedTestName).set_span(name_span.into()),
+        )?;
+
+        self.consume(TokenType::LeftBrace, error!(ParserError::ExpectedTestBlock))?;
+
+        let body = self.block_statement()?;
+
+        Ok(Stmt::Test(Box::new(TestStmt { name, body })))
+    }
+
     fn statement(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
         if self.r#match(vec![TokenType::Print])? {
             self.print_statement()
@@ -452,17 +1239,95 @@ impl Parser {
         } else if self.r#match(vec![TokenType::If])? {
             self.if_statement()
         } else if self.r#match(vec![TokenType::While])? {
-            self.while_statement()
+            self.while_statement(None)
         } else if self.r#match(vec![TokenType::For])? {
-            self.for_statement()
+            self.for_statement(None)
+        } else if self.check(TokenType::Identifier)?
+            && self.peek_next()?.token_type == TokenType::Colon
+        {
+            self.advance()?;
+            self.labelled_statement()
+        } else if self.r#match(vec![TokenType::Break, TokenType::Continue])? {
+            self.loop_control_statement()
         } else if self.r#match(vec![TokenType::Fn])? {
             self.function()
+        } else if self.r#match(vec![TokenType::Return])? {
+            self.return_statement()
         } else {
             self.expression_statement()
         }
     }
 
+    fn destructure_pattern(&mut self) -> Result<DestructurePattern, ErrorCascade<ParserError>> {
+        let opening = self.previous()?;
+        let closing = match opening.token_type {
+            TokenType::LeftBracket => TokenType::RightBracket,
+            _ => TokenType::RightBrace,
+        };
+
+        let mut names: Vec<Token> = vec![];
+        while !self.check(closing.clone())? {
+            let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+            names.push(self.consume(
+                TokenType::Identifier,
+                error!(ParserError::ExpectedDestructureName).set_span(span.into()),
+            )?);
+            if !self.r#match(vec![TokenType::Comma])? {
+                break;
+            }
+        }
+
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        let closing_symbol = match closing {
+            TokenType::RightBracket => "]",
+            _ => "}",
+        };
+        self.consume(
+            closing,
+            error!(ParserError::UnterminatedDestructure(
+                closing_symbol.to_string()
+            ))
+            .set_span(span.into()),
+        )?;
+
+        Ok(match opening.token_type {
+            TokenType::LeftBracket => DestructurePattern::List(opening, names),
+            _ => DestructurePattern::Object(opening, names),
+        })
+    }
+
+    fn destructure_declaration(
+        &mut self,
+        keyword: Token,
+    ) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let pattern = self.destructure_pattern()?;
+
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        self.consume(
+            TokenType::Equal,
+            error!(ParserError::ExpectedEqual).set_span(span.into()),
+        )?;
+        let initializer = self.expression()?;
+
+        self.consume(
+            TokenType::Semicolon,
+            error!(ParserError::UnterminatedStatement),
+        )?;
+
+        Ok(Stmt::Destructure(Box::new(DestructureStmt {
+            keyword,
+            pattern,
+            initializer,
+        })))
+    }
+
     pub fn var_declaration(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+        if self.r#match(vec![TokenType::LeftBracket, TokenType::LeftBrace])? {
+            return self.destructure_declaration(keyword);
+        }
+
+        let doc = self.declaration_doc()?;
         self.consume(
             TokenType::Identifier,
             error!(ParserError::ExpectedIdentifier),
@@ -470,6 +1335,8 @@ impl Parser {
 
         let name = self.previous()?;
 
+        let type_annotation = self.optional_type_annotation(TokenType::Colon)?;
+
         let initializer = if self.r#match(vec![TokenType::Equal])? {
             Some(self.expression()?)
         } else {
@@ -481,12 +1348,84 @@ impl Parser {
             error!(ParserError::UnterminatedStatement),
         )?;
 
-        Ok(Stmt::Variable(Box::new(VariableStmt { name, initializer })))
+        Ok(Stmt::Variable(Box::new(VariableStmt {
+            name,
+            type_annotation,
+            initializer,
+            doc,
+        })))
+    }
+
+    fn import_declaration(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        let path = self.consume(
+            TokenType::String,
+            error!(ParserError::ExpectedModulePath).set_span(span.into()),
+        )?;
+
+        let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+        self.consume(
+            TokenType::As,
+            error!(ParserError::ExpectedModuleAlias).set_span(span.clone().into()),
+        )?;
+        let alias = self.consume(
+            TokenType::Identifier,
+            error!(ParserError::ExpectedModuleAlias).set_span(span.into()),
+        )?;
+
+        self.consume(
+            TokenType::Semicolon,
+            error!(ParserError::UnterminatedStatement),
+        )?;
+
+        Ok(Stmt::Import(Box::new(ImportStmt {
+            keyword,
+            path,
+            alias,
+        })))
+    }
+
+    fn export_declaration(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        let keyword = self.previous()?;
+        let span = keyword
+            .span
+            .clone()
+            .ok_or(error!(ParserError::MissingSpan))?;
+
+        let declaration = if self.r#match(vec![TokenType::Let])? {
+            self.var_declaration()?
+        } else if self.r#match(vec![TokenType::Fn])? {
+            self.function()?
+        } else {
+            Err(error!(ParserError::InvalidExport).set_span(span.into()))?
+        };
+
+        Ok(Stmt::Export(Box::new(ExportStmt {
+            keyword,
+            declaration,
+        })))
+    }
+
+    fn top_level_declaration(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
+        if self.r#match(vec![TokenType::Export])? {
+            self.export_declaration()
+        } else {
+            self.declaration()
+        }
     }
 
     pub fn declaration(&mut self) -> Result<Stmt, ErrorCascade<ParserError>> {
-        if self.r#match(vec![TokenType::Let])? {
+        if self.check(TokenType::Export)? {
+            let span = self.peek()?.span.ok_or(error!(ParserError::MissingSpan))?;
+            Err(error!(ParserError::InvalidExport).set_span(span.into()))
+        } else if self.r#match(vec![TokenType::Let])? {
             self.var_declaration()
+        } else if self.r#match(vec![TokenType::Import])? {
+            self.import_declaration()
+        } else if self.is_test_declaration()? {
+            self.test_declaration()
         } else {
             self.statement()
         }
@@ -499,7 +1438,7 @@ impl Parser {
         let mut statements: Vec<Stmt> = vec![];
 
         while !self.is_at_end()? {
-            statements.push(self.declaration()?);
+            statements.push(self.top_level_declaration()?);
         }
 
         Ok(statements)
@@ -648,4 +1587,84 @@ mod parser_tests {
         let parser_result = parser.parse(lexer_result.unwrap());
         assert!(parser_result.is_ok());
     }
+
+    #[test]
+    fn parses_type_annotations() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            let count: integer = 1;
+            fn add(a: number, b: number) -> number {
+                return a + b;
+            }
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+    }
+
+    #[test]
+    fn parses_module_declarations() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            import \"math.fun\" as math;
+            export let tau = math.pi * 2;
+            export fn area(radius) {
+                return math.pi * radius * radius;
+            }
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+    }
+
+    #[test]
+    fn attaches_doc_comments_to_declarations() {
+        let mut lexer = Lexer::new();
+        let lexer_result = lexer.tokenize(
+            "
+            /// The circle constant.
+            export let tau = 6.28;
+            /// Doubles a number.
+            fn double(x) {
+                return x * 2;
+            }
+            let plain = 1;
+            ",
+        );
+        assert!(lexer_result.is_ok());
+
+        let mut parser = Parser::new();
+        let parser_result = parser.parse(lexer_result.unwrap());
+        assert!(parser_result.is_ok());
+
+        let docs: Vec<Option<String>> = parser_result
+            .unwrap()
+            .iter()
+            .map(|statement| match statement {
+                Stmt::Export(export_statement) => match &export_statement.declaration {
+                    Stmt::Variable(variable_statement) => variable_statement.doc.clone(),
+                    _ => None,
+                },
+                Stmt::Function(function_statement) => function_statement.doc.clone(),
+                Stmt::Variable(variable_statement) => variable_statement.doc.clone(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            docs,
+            vec![
+                Some("The circle constant.".to_string()),
+                Some("Doubles a number.".to_string()),
+                None
+            ]
+        );
+    }
 }
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Range {
+    start: i64,
+    end: i64,
+    inclusive: bool,
+}
+
+impl Range {
+    pub fn new(start: i64, end: i64, inclusive: bool) -> Self {
+        Self {
+            start,
+            end,
+            inclusive,
+        }
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    pub fn exclusive_end(&self) -> i128 {
+        self.end as i128 + self.inclusive as i128
+    }
+
+    pub fn len(&self) -> usize {
+        (self.exclusive_end() - self.start as i128).clamp(0, usize::MAX as i128) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<i64> {
+        if index < self.len() {
+            Some((self.start as i128 + index as i128) as i64)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains_integer(&self, value: i64) -> bool {
+        if self.inclusive {
+            self.start <= value && value <= self.end
+        } else {
+            self.start <= value && value < self.end
+        }
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        if self.inclusive {
+            (self.start as f64) <= value && value <= self.end as f64
+        } else {
+            (self.start as f64) <= value && value < self.end as f64
+        }
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.inclusive {
+            write!(f, "{}..={}", self.start, self.end)
+        } else {
+            write!(f, "{}..{}", self.start, self.end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod range_tests {
+    use super::*;
+
+    #[test]
+    fn measures_ranges() {
+        assert_eq!(Range::new(1, 4, false).len(), 3);
+        assert_eq!(Range::new(1, 4, true).len(), 4);
+        assert_eq!(Range::new(4, 1, false).len(), 0);
+        assert_eq!(Range::new(2, 5, true).get(3), Some(5));
+        assert_eq!(Range::new(2, 5, false).get(3), None);
+        assert_eq!(Range::new(i64::MAX - 1, i64::MAX, true).len(), 2);
+        assert_eq!(
+            Range::new(i64::MAX - 1, i64::MAX, true).get(1),
+            Some(i64::MAX)
+        );
+        assert_eq!(Range::new(i64::MIN, i64::MAX, true).len(), usize::MAX);
+    }
+
+    #[test]
+    fn checks_membership() {
+        assert!(Range::new(1, 3, false).contains(2.5));
+        assert!(!Range::new(1, 3, false).contains(3.0));
+        assert!(Range::new(1, 3, true).contains(3.0));
+        assert!(Range::new(0, i64::MAX, true).contains_integer(i64::MAX));
+        assert!(!Range::new((1 << 53) + 1, (1 << 53) + 3, false).contains_integer(1 << 53));
+    }
+}
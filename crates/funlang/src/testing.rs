@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use funlang_error::ErrorCascade;
+
+use crate::{errors::InterpreterError, token::Span};
+
+#[derive(Debug)]
+pub struct TestResult {
+    pub name: String,
+    pub span: Option<Span>,
+    pub error: Option<ErrorCascade<InterpreterError>>,
+}
+
+impl TestResult {
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TestSummary {
+    pub results: Vec<TestResult>,
+}
+
+impl TestSummary {
+    pub fn new() -> Self {
+        Self {
+            results: Vec::new(),
+        }
+    }
+
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|result| result.passed()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.passed()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+}
+
+impl Display for TestSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for result in &self.results {
+            let location = match &result.span {
+                Some(span) => format!(" [line {}:{}]", span.line, span.col),
+                None => String::new(),
+            };
+            match &result.error {
+                None => writeln!(f, "test {}{} ... ok", result.name, location)?,
+                Some(error) => {
+                    writeln!(f, "test {}{} ... FAILED", result.name, location)?;
+                    writeln!(f, "    {}", error)?;
+                }
+            }
+        }
+        write!(
+            f,
+            "test result: {}. {} passed; {} failed",
+            if self.is_success() { "ok" } else { "FAILED" },
+            self.passed(),
+            self.failed()
+        )
+    }
+}
@@ -9,6 +9,7 @@ pub enum TokenType {
     // Literals
     Identifier,
     String,
+    Interpolation,
     Number,
 
     // One or two character tokens,
@@ -20,6 +21,22 @@ pub enum TokenType {
     GreaterEqual,
     Less,
     LessEqual,
+    Arrow,
+    FatArrow,
+    DotDot,
+    DotDotEqual,
+    DotDotDot,
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
+    PercentEqual,
+    StarStar,
+    LessLess,
+    GreaterGreater,
+    QuestionQuestion,
+    QuestionDot,
+    PipeGreater,
 
     // KEYWORDS
     And,
@@ -36,6 +53,14 @@ pub enum TokenType {
     While,
     This,
     Fn,
+    Import,
+    Export,
+    As,
+    Div,
+    Break,
+    Continue,
+    In,
+    Match,
 
     // Single-character
     LeftBracket,
@@ -45,12 +70,19 @@ pub enum TokenType {
     LeftBrace,
     RightBrace,
     Comma,
+    Colon,
     Dot,
     Minus,
     Plus,
     Semicolon,
     Slash,
     Star,
+    Percent,
+    Ampersand,
+    Pipe,
+    Caret,
+    Tilde,
+    Question,
 
     EOF,
 }
@@ -72,9 +104,58 @@ impl TokenType {
             "while" => Some(TokenType::While),
             "this" => Some(TokenType::This),
             "fn" => Some(TokenType::Fn),
+            "import" => Some(TokenType::Import),
+            "export" => Some(TokenType::Export),
+            "as" => Some(TokenType::As),
+            "div" => Some(TokenType::Div),
+            "break" => Some(TokenType::Break),
+            "continue" => Some(TokenType::Continue),
+            "in" => Some(TokenType::In),
+            "match" => Some(TokenType::Match),
             _ => None,
         }
     }
+
+    pub fn compound_operator(&self) -> Option<TokenType> {
+        match self {
+            TokenType::PlusEqual => Some(TokenType::Plus),
+            TokenType::MinusEqual => Some(TokenType::Minus),
+            TokenType::StarEqual => Some(TokenType::Star),
+            TokenType::SlashEqual => Some(TokenType::Slash),
+            TokenType::PercentEqual => Some(TokenType::Percent),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> String {
+        match self {
+            TokenType::Plus => "+",
+            TokenType::Minus => "-",
+            TokenType::Star => "*",
+            TokenType::Slash => "/",
+            TokenType::Percent => "%",
+            TokenType::StarStar => "**",
+            TokenType::Div => "div",
+            TokenType::Ampersand => "&",
+            TokenType::Pipe => "|",
+            TokenType::Caret => "^",
+            TokenType::Tilde => "~",
+            TokenType::LessLess => "<<",
+            TokenType::GreaterGreater => ">>",
+            TokenType::Greater => ">",
+            TokenType::GreaterEqual => ">=",
+            TokenType::Less => "<",
+            TokenType::LessEqual => "<=",
+            TokenType::EqualEqual => "==",
+            TokenType::BangEqual => "!=",
+            TokenType::Bang => "!",
+            TokenType::In => "in",
+            TokenType::And => "and",
+            TokenType::Or => "or",
+            token_type => return token_type.to_string(),
+        }
+        .to_string()
+    }
 }
 
 impl Display for TokenType {
@@ -88,11 +169,24 @@ pub struct Span {
     pub line: usize,
     pub col: usize,
     pub len: usize,
+    pub start: usize,
+    pub end: usize,
 }
 
 impl Span {
     pub fn new(line: usize, col: usize, len: usize) -> Self {
-        Self { line, col, len }
+        Self {
+            line,
+            col,
+            len,
+            start: 0,
+            end: 0,
+        }
+    }
+    pub fn set_range(mut self, start: usize, end: usize) -> Self {
+        self.start = start;
+        self.end = end;
+        self
     }
 }
 
@@ -112,6 +206,7 @@ pub struct Token {
     pub lexeme: Option<String>,
     pub literal_data: Option<LiteralData>,
     pub span: Option<Span>,
+    pub doc: Option<String>,
 }
 
 impl Token {
@@ -121,6 +216,7 @@ impl Token {
             span: None,
             lexeme: None,
             literal_data: None,
+            doc: None,
         }
     }
     pub fn set_literal_data(mut self, value: LiteralData) -> Self {
@@ -135,4 +231,8 @@ impl Token {
         self.lexeme = Some(value);
         self
     }
+    pub fn set_doc(mut self, value: String) -> Self {
+        self.doc = Some(value);
+        self
+    }
 }
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use crate::literal::LiteralData;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Type {
+    Any,
+    Number,
+    Integer,
+    Float,
+    String,
+    Bool,
+    Function,
+    Module,
+    Range,
+    List,
+    Null,
+}
+
+impl Type {
+    pub fn from_name(name: &str) -> Option<Type> {
+        match name {
+            "any" => Some(Type::Any),
+            "number" => Some(Type::Number),
+            "integer" => Some(Type::Integer),
+            "float" => Some(Type::Float),
+            "string" => Some(Type::String),
+            "bool" => Some(Type::Bool),
+            "function" => Some(Type::Function),
+            "module" => Some(Type::Module),
+            "range" => Some(Type::Range),
+            "list" => Some(Type::List),
+            "null" => Some(Type::Null),
+            _ => None,
+        }
+    }
+
+    pub fn of(literal: &LiteralData) -> Type {
+        match literal {
+            LiteralData::String(_) => Type::String,
+            LiteralData::Integer(_) => Type::Integer,
+            LiteralData::Float(_) => Type::Float,
+            LiteralData::Bool(_) => Type::Bool,
+            LiteralData::Function(_) | LiteralData::NativeFunction(_) => Type::Function,
+            LiteralData::Module(_) => Type::Module,
+            LiteralData::Range(_) => Type::Range,
+            LiteralData::List(_) => Type::List,
+            LiteralData::None => Type::Null,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Type::Number | Type::Integer | Type::Float)
+    }
+
+    pub fn accepts(&self, other: &Type) -> bool {
+        match (self, other) {
+            (Type::Any, _) | (_, Type::Any) => true,
+            (Type::Number, other) => other.is_numeric(),
+            (expected, other) => expected == other,
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Type::Any => "any",
+            Type::Number => "number",
+            Type::Integer => "integer",
+            Type::Float => "float",
+            Type::String => "string",
+            Type::Bool => "bool",
+            Type::Function => "function",
+            Type::Module => "module",
+            Type::Range => "range",
+            Type::List => "list",
+            Type::Null => "null",
+        };
+        write!(f, "{}", name)
+    }
+}
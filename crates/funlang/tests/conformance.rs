@@ -0,0 +1,187 @@
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use funlang::{
+    checker::TypeChecker, interpreter::Interpreter, lexer::Lexer, modules::FileResolver,
+    output::Output, parser::Parser,
+};
+
+const PROGRAMS_DIRECTORY: &str = "tests/programs";
+
+#[derive(Debug, PartialEq)]
+enum Stage {
+    Lexer,
+    Parser,
+    TypeChecker,
+    Runtime,
+}
+
+#[derive(Debug, PartialEq)]
+struct Diagnostic {
+    stage: Stage,
+    message: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Outcome {
+    output: Vec<String>,
+    warnings: Vec<String>,
+    diagnostic: Option<Diagnostic>,
+}
+
+fn collect_programs(directory: &Path, programs: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(directory)
+        .unwrap_or_else(|error| panic!("could not read `{}`: {}", directory.display(), error));
+
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_programs(&path, programs);
+        } else if path.extension().is_some_and(|extension| extension == "fun") {
+            programs.push(path);
+        }
+    }
+}
+
+fn parse_expectations(source: &str) -> Outcome {
+    let mut output: Vec<String> = vec![];
+    let mut warnings: Vec<String> = vec![];
+    let mut diagnostic: Option<Diagnostic> = None;
+
+    for line in source.lines() {
+        let Some((_, comment)) = line.split_once("// expect") else {
+            continue;
+        };
+
+        if let Some(expected_line) = comment.strip_prefix(": ") {
+            output.push(expected_line.to_string());
+            continue;
+        }
+
+        if let Some(warning) = comment.strip_prefix(" warning: ") {
+            warnings.push(warning.to_string());
+            continue;
+        }
+
+        for (prefix, stage) in [
+            (" lexer error: ", Stage::Lexer),
+            (" parser error: ", Stage::Parser),
+            (" type error: ", Stage::TypeChecker),
+            (" runtime error: ", Stage::Runtime),
+        ] {
+            if let Some(message) = comment.strip_prefix(prefix) {
+                diagnostic = Some(Diagnostic {
+                    stage,
+                    message: message.to_string(),
+                });
+                break;
+            }
+        }
+    }
+
+    Outcome {
+        output,
+        warnings,
+        diagnostic,
+    }
+}
+
+fn run_program(program: &Path, source: &str) -> Outcome {
+    let lines = Rc::new(RefCell::new(Vec::<String>::new()));
+    let captured_lines = lines.clone();
+    let mut warnings: Vec<String> = vec![];
+
+    let diagnostic = 'run: {
+        let mut lexer = Lexer::new();
+        let tokens = match lexer.tokenize(source) {
+            Ok(tokens) => tokens,
+            Err(error) => {
+                break 'run Some(Diagnostic {
+                    stage: Stage::Lexer,
+                    message: error.to_string(),
+                })
+            }
+        };
+
+        let mut parser = Parser::new();
+        let statements = match parser.parse(tokens) {
+            Ok(statements) => statements,
+            Err(error) => {
+                break 'run Some(Diagnostic {
+                    stage: Stage::Parser,
+                    message: error.to_string(),
+                })
+            }
+        };
+
+        let mut checker = TypeChecker::new();
+        let checked = checker.check(&statements);
+        warnings.extend(checker.warnings().iter().map(|warning| warning.to_string()));
+        if let Err(error) = checked {
+            break 'run Some(Diagnostic {
+                stage: Stage::TypeChecker,
+                message: error.to_string(),
+            });
+        }
+
+        let mut interpreter = Interpreter::new()
+            .set_output(Output::callback(move |line| {
+                captured_lines.borrow_mut().push(line.to_string())
+            }))
+            .set_resolver(FileResolver::new(program.parent().unwrap()));
+        interpreter
+            .interpret(statements)
+            .err()
+            .map(|error| Diagnostic {
+                stage: Stage::Runtime,
+                message: error.to_string(),
+            })
+    };
+
+    let output = lines.borrow().clone();
+    Outcome {
+        output,
+        warnings,
+        diagnostic,
+    }
+}
+
+#[test]
+fn runs_conformance_programs() {
+    let mut programs: Vec<PathBuf> = vec![];
+    collect_programs(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join(PROGRAMS_DIRECTORY),
+        &mut programs,
+    );
+    programs.sort();
+    assert!(!programs.is_empty());
+
+    let mut failures: Vec<String> = vec![];
+
+    for program in &programs {
+        let source = fs::read_to_string(program).unwrap();
+        let expected = parse_expectations(&source);
+        let actual = run_program(program, &source);
+
+        if expected != actual {
+            failures.push(format!(
+                "{}\n  expected: {:?}\n    actual: {:?}",
+                program.display(),
+                expected,
+                actual
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} conformance programs failed:\n{}",
+        failures.len(),
+        programs.len(),
+        failures.join("\n")
+    );
+}
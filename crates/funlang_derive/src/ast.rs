@@ -30,7 +30,7 @@ pub fn build_struct(variant: &Variant, input_identifier: &Ident) -> TokenStream
         let formatted_identifier_string = format!("{}{}", identifier_string, input_identifier);
         syn::parse_str(&formatted_identifier_string).unwrap()
     };
-    let fields = match variant.attrs.get(0) {
+    let fields = match variant.attrs.first() {
         Some(attribute) => build_fields(attribute),
         _ => None,
     };
@@ -58,11 +58,11 @@ pub fn build_fields(attribute: &Attribute) -> Option<Vec<TokenStream>> {
                     let splitted_named_value = trimmed_named_value.split_once(':').unwrap();
 
                     let identifier = &splitted_named_value.0.trim();
-                    let identifier: Expr = syn::parse_str(&identifier).unwrap();
+                    let identifier: Expr = syn::parse_str(identifier).unwrap();
 
                     let parsed_type = &splitted_named_value.1.trim();
-                    let parsed_type: Type = syn::parse_str(&parsed_type).unwrap();
-                    
+                    let parsed_type: Type = syn::parse_str(parsed_type).unwrap();
+
                     quote!(pub #identifier : #parsed_type,)
                 })
                 .collect();
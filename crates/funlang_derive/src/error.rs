@@ -75,7 +75,7 @@ fn build_display_arms(data: &Data) -> Vec<TokenStream> {
 
 fn build_display_arm(variant: &Variant) -> TokenStream {
     let identifier = &variant.ident;
-    let error_message = match variant.attrs.get(0) {
+    let error_message = match variant.attrs.first() {
         Some(attribute) => match &attribute.meta {
             Meta::NameValue(meta_name_value) => {
                 let expr = &meta_name_value.value;
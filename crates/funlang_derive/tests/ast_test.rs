@@ -1,3 +1,4 @@
+#[allow(dead_code)]
 #[derive(funlang_derive::Ast, Debug)]
 enum Expr {
     #[production(left: String, right: core::option::Option<String>)]